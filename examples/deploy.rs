@@ -7,7 +7,7 @@ use cw_orch::{
     prelude::*,
 };
 use cw_web31_dns::{
-    models::Config,
+    models::{Config, GiftingPolicy},
     msg::{InstantiateMsg, MigrateMsg},
     token::{Token, TokenAmount},
     Contract, ContractExecuteMsgFns, ContractQueryMsgFns,
@@ -34,6 +34,13 @@ pub fn main() -> anyhow::Result<()> {
             &InstantiateMsg {
                 fee_recipient: Addr::unchecked(FEE_COLLECTION_ADDR),
                 max_name_len: 20,
+                allowed_hrps: vec![],
+                verify_contract_targets: false,
+                probe_render_targets: false,
+                require_target_admin: false,
+                gifting: GiftingPolicy::Anyone,
+                release_cooldown: 30 * 24 * 60 * 60,
+                referral_share: 0,
                 price: TokenAmount {
                     amount: Uint128::from(1u64),
                     token: Token::Denom("ujuno".to_string()),
@@ -52,7 +59,10 @@ pub fn main() -> anyhow::Result<()> {
             None,
         );
     } else {
-        contract.migrate_if_needed(&MigrateMsg {})?;
+        contract.migrate_if_needed(&MigrateMsg {
+            admin: None,
+            limit: None,
+        })?;
     }
 
     // TODO: can call any necessary execution messages here like adding admin, etc.
@@ -1,11 +1,47 @@
 use crate::error::ContractError;
+use crate::execute::batch_register::exec_batch_register;
+use crate::execute::batch_update_metadata::exec_batch_update_metadata;
+use crate::execute::claim_name::exec_claim_name;
+use crate::execute::clear_metadata_fields::exec_clear_metadata_fields;
+use crate::execute::create_voucher::exec_create_voucher;
 use crate::execute::register::exec_register;
+use crate::execute::release_name::exec_release_name;
+use crate::execute::replace_metadata::exec_replace_metadata;
+use crate::execute::revoke_voucher::exec_revoke_voucher;
+use crate::execute::set_address::exec_set_address;
+use crate::execute::set_content_hash::exec_set_content_hash;
+use crate::execute::set_controller::exec_set_controller;
+use crate::execute::set_primary_contract_name::exec_set_primary_contract_name;
+use crate::execute::set_primary_name::exec_set_primary_name;
+use crate::execute::set_text_record::exec_set_text_record;
+use crate::execute::transfer::exec_transfer;
+use crate::execute::unset_address::exec_unset_address;
+use crate::execute::unset_text_record::exec_unset_text_record;
+use crate::execute::update_config::exec_update_config;
+use crate::execute::update_contract::exec_update_contract;
 use crate::execute::update_metadata::exec_update_metadata;
 use crate::execute::Context;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, NameRecordsQueryMsg, NameRecordsSortKey, QueryMsg, SortOrder,
+};
+use crate::query::address_records::query_address_records;
+use crate::query::batch_name_records::query_batch_name_records;
+use crate::query::batch_resolve::query_batch_resolve;
+use crate::query::image_asset::query_image_asset;
+use crate::query::is_available::query_is_available;
 use crate::query::name_record::query_name_record;
 use crate::query::name_records::query_name_records;
+use crate::query::names_by_contract::query_names_by_contract;
+use crate::query::names_by_keyword::query_names_by_keyword;
+use crate::query::names_by_owner::query_names_by_owner;
+use crate::query::primary_name::query_primary_name;
+use crate::query::referral_earnings::query_referral_earnings;
 use crate::query::render::query_render;
+use crate::query::resolve_address::query_resolve_address;
+use crate::query::search_names::query_search_names;
+use crate::query::text_record::query_text_record;
+use crate::query::text_records::query_text_records;
+use crate::query::voucher::query_voucher;
 use crate::query::{config::query_config, ReadonlyContext};
 use crate::state;
 use cosmwasm_std::{entry_point, to_json_binary};
@@ -36,7 +72,26 @@ pub fn execute(
     let ctx = Context { deps, env, info };
     match msg {
         ExecuteMsg::Register(msg) => exec_register(ctx, msg),
+        ExecuteMsg::BatchRegister(msg) => exec_batch_register(ctx, msg),
         ExecuteMsg::UpdateMetadata(msg) => exec_update_metadata(ctx, msg),
+        ExecuteMsg::BatchUpdateMetadata(msg) => exec_batch_update_metadata(ctx, msg),
+        ExecuteMsg::ReplaceMetadata(msg) => exec_replace_metadata(ctx, msg),
+        ExecuteMsg::ClearMetadataFields(msg) => exec_clear_metadata_fields(ctx, msg),
+        ExecuteMsg::UpdateContract(msg) => exec_update_contract(ctx, msg),
+        ExecuteMsg::SetContentHash(msg) => exec_set_content_hash(ctx, msg),
+        ExecuteMsg::SetController(msg) => exec_set_controller(ctx, msg),
+        ExecuteMsg::Transfer(msg) => exec_transfer(ctx, msg),
+        ExecuteMsg::SetPrimaryContractName(msg) => exec_set_primary_contract_name(ctx, msg),
+        ExecuteMsg::SetPrimaryName(msg) => exec_set_primary_name(ctx, msg),
+        ExecuteMsg::SetTextRecord(msg) => exec_set_text_record(ctx, msg),
+        ExecuteMsg::UnsetTextRecord(msg) => exec_unset_text_record(ctx, msg),
+        ExecuteMsg::SetAddress(msg) => exec_set_address(ctx, msg),
+        ExecuteMsg::UnsetAddress(msg) => exec_unset_address(ctx, msg),
+        ExecuteMsg::UpdateConfig(msg) => exec_update_config(ctx, msg),
+        ExecuteMsg::ClaimName(msg) => exec_claim_name(ctx, msg),
+        ExecuteMsg::ReleaseName(msg) => exec_release_name(ctx, msg),
+        ExecuteMsg::CreateVoucher(msg) => exec_create_voucher(ctx, msg),
+        ExecuteMsg::RevokeVoucher(msg) => exec_revoke_voucher(ctx, msg),
     }
 }
 
@@ -52,6 +107,31 @@ pub fn query(
         QueryMsg::NameRecord { contract } => to_json_binary(&query_name_record(ctx, contract)?),
         QueryMsg::Render(msg) => to_json_binary(&query_render(ctx, msg)?),
         QueryMsg::NameRecords(msg) => to_json_binary(&query_name_records(ctx, msg)?),
+        QueryMsg::RecentNameRecords { limit, cursor } => to_json_binary(&query_name_records(
+            ctx,
+            NameRecordsQueryMsg {
+                limit,
+                cursor,
+                order: Some(SortOrder::Descending),
+                sort_by: Some(NameRecordsSortKey::CreatedAt),
+                ..Default::default()
+            },
+        )?),
+        QueryMsg::NamesByOwner(msg) => to_json_binary(&query_names_by_owner(ctx, msg)?),
+        QueryMsg::NamesByContract(msg) => to_json_binary(&query_names_by_contract(ctx, msg)?),
+        QueryMsg::NamesByKeyword(msg) => to_json_binary(&query_names_by_keyword(ctx, msg)?),
+        QueryMsg::SearchNames(msg) => to_json_binary(&query_search_names(ctx, msg)?),
+        QueryMsg::IsAvailable { name } => to_json_binary(&query_is_available(ctx, name)?),
+        QueryMsg::BatchNameRecords { names } => to_json_binary(&query_batch_name_records(ctx, names)?),
+        QueryMsg::BatchResolve { names_or_addresses } => to_json_binary(&query_batch_resolve(ctx, names_or_addresses)?),
+        QueryMsg::TextRecord { name, key } => to_json_binary(&query_text_record(ctx, name, key)?),
+        QueryMsg::TextRecords { name } => to_json_binary(&query_text_records(ctx, name)?),
+        QueryMsg::ResolveAddress { name, chain } => to_json_binary(&query_resolve_address(ctx, name, chain)?),
+        QueryMsg::AddressRecords { name } => to_json_binary(&query_address_records(ctx, name)?),
+        QueryMsg::PrimaryName { address } => to_json_binary(&query_primary_name(ctx, address)?),
+        QueryMsg::ImageAsset { name, kind } => to_json_binary(&query_image_asset(ctx, name, kind)?),
+        QueryMsg::Voucher { code } => to_json_binary(&query_voucher(ctx, code)?),
+        QueryMsg::ReferralEarnings { referrer } => to_json_binary(&query_referral_earnings(ctx, referrer)?),
     }?;
     Ok(result)
 }
@@ -59,9 +139,9 @@ pub fn query(
 #[entry_point]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
-    _msg: MigrateMsg,
+    env: Env,
+    msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(Response::default())
+    state::migrate(deps, env, msg)
 }
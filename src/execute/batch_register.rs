@@ -0,0 +1,50 @@
+use crate::{error::ContractError, msg::BatchRegisterMsg};
+use cosmwasm_std::Response;
+
+use super::{
+    register::{collect_fees, register_name},
+    Context,
+};
+
+/// Max number of names registered or updated in one batch
+pub const MAX_BATCH_EXECUTE_SIZE: u8 = 25;
+
+/// Register several names paid for with a single combined payment covering
+/// each name's fee after its voucher discount. Either
+/// all names are registered or, if any of them fails, none are.
+pub fn exec_batch_register(
+    ctx: Context,
+    msg: BatchRegisterMsg,
+) -> Result<Response, ContractError> {
+    let Context { mut deps, info, env } = ctx;
+
+    let BatchRegisterMsg { names } = msg;
+
+    if names.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "Batch must not be empty".to_string(),
+        });
+    }
+    if names.len() > MAX_BATCH_EXECUTE_SIZE as usize {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_BATCH_EXECUTE_SIZE,
+        });
+    }
+
+    let mut attrs = vec![];
+    let mut fees = vec![];
+    for msg in names {
+        let (name_attrs, fee) = register_name(deps.branch(), &env, &info.sender, msg)?;
+        attrs.extend(name_attrs);
+        fees.push(fee);
+    }
+
+    // Ensure user has sent payment for all names and add transfer submsgs to
+    // send the fees
+    let transfers = collect_fees(deps.storage, &info.funds, fees)?;
+
+    Ok(Response::new()
+        .add_submessages(transfers)
+        .add_attribute("action", "batch_register")
+        .add_attributes(attrs))
+}
@@ -0,0 +1,44 @@
+use crate::{
+    error::ContractError,
+    msg::{BatchUpdateMetadataMsg, UpdateMetadataMsg},
+};
+use cosmwasm_std::Response;
+
+use super::{
+    batch_register::MAX_BATCH_EXECUTE_SIZE,
+    update_metadata::{merge_metadata, update_name_metadata},
+    Context,
+};
+
+/// Update the metadata of several names the sender controls. Either all
+/// updates are applied or, if any of them fails, none are.
+pub fn exec_batch_update_metadata(
+    ctx: Context,
+    msg: BatchUpdateMetadataMsg,
+) -> Result<Response, ContractError> {
+    let Context { mut deps, info, .. } = ctx;
+
+    let BatchUpdateMetadataMsg { updates } = msg;
+
+    if updates.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "Batch must not be empty".to_string(),
+        });
+    }
+    if updates.len() > MAX_BATCH_EXECUTE_SIZE as usize {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_BATCH_EXECUTE_SIZE,
+        });
+    }
+
+    let mut resp = Response::new().add_attribute("action", "batch_update_metadata");
+
+    for UpdateMetadataMsg { name, meta } in updates {
+        update_name_metadata(deps.branch(), &info.sender, &name, |prev_meta| {
+            merge_metadata(prev_meta, meta)
+        })?;
+        resp = resp.add_attribute("name", name);
+    }
+
+    Ok(resp)
+}
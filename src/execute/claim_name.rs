@@ -0,0 +1,68 @@
+use crate::{
+    error::ContractError,
+    models::NameMetadata,
+    msg::ClaimNameMsg,
+    state::{
+        clear_name_records, is_target_admin, update_keyword_index, NAME_METADATA, NAME_RECORDS, PRIMARY_NAMES,
+        REQUIRE_TARGET_ADMIN,
+    },
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Take over a name pointing at a contract the sender is the admin or
+/// creator of, e.g. one registered by a squatter. Only possible while
+/// registration is restricted to target admins. Everything the previous
+/// owner set up, i.e. the controller, metadata, text and address records and
+/// their primary name, is cleared.
+pub fn exec_claim_name(
+    ctx: Context,
+    msg: ClaimNameMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, env } = ctx;
+
+    let ClaimNameMsg { name } = msg;
+
+    if !REQUIRE_TARGET_ADMIN.load(deps.storage)? {
+        return Err(ContractError::NotAuthorized {
+            reason: "Names can only be claimed while registration is restricted to target admins".to_string(),
+        });
+    }
+
+    let mut record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the admin or creator of the target contract
+    let is_claimable = match &record.contract {
+        Some(contract_addr) => is_target_admin(deps.as_ref(), &env, &info.sender, contract_addr),
+        None => false,
+    };
+    if !is_claimable {
+        return Err(ContractError::NotAuthorized {
+            reason: "Only the admin or creator of the target contract can claim this name".to_string(),
+        });
+    }
+
+    // Drop the previous owner's primary name if it's this name
+    if PRIMARY_NAMES.may_load(deps.storage, &record.owner)?.as_ref() == Some(&name) {
+        PRIMARY_NAMES.remove(deps.storage, &record.owner);
+    }
+
+    record.owner = info.sender;
+    record.controller = None;
+
+    NAME_RECORDS.save(deps.storage, &name, &record)?;
+
+    // Reset the metadata and drop the records left by the previous owner
+    if let Some(meta) = NAME_METADATA.may_load(deps.storage, &name)? {
+        update_keyword_index(deps.storage, &name, meta.keywords.as_ref(), None)?;
+    }
+    NAME_METADATA.save(deps.storage, &name, &NameMetadata::default())?;
+    clear_name_records(deps.storage, &name)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "claim_name"),
+        attr("name", name),
+        attr("owner", record.owner.to_string()),
+    ]))
+}
@@ -0,0 +1,23 @@
+use crate::{error::ContractError, msg::ClearMetadataFieldsMsg};
+use cosmwasm_std::Response;
+
+use super::{update_metadata::update_name_metadata, Context};
+
+/// Reset individual metadata fields of a name back to unset. Only the
+/// controller may do this.
+pub fn exec_clear_metadata_fields(
+    ctx: Context,
+    msg: ClearMetadataFieldsMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let ClearMetadataFieldsMsg { name, fields } = msg;
+
+    update_name_metadata(deps, &info.sender, &name, |meta| {
+        for field in fields.iter() {
+            meta.clear(field);
+        }
+    })?;
+
+    Ok(Response::new().add_attribute("action", "clear_metadata_fields"))
+}
@@ -0,0 +1,51 @@
+use crate::{
+    error::ContractError,
+    models::Voucher,
+    msg::CreateVoucherMsg,
+    state::{ensure_admin, VOUCHERS},
+    utils::validate_voucher_code,
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Create a discount voucher redeemable when registering names. Only the
+/// admin may do this.
+pub fn exec_create_voucher(
+    ctx: Context,
+    msg: CreateVoucherMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let CreateVoucherMsg {
+        code,
+        discount,
+        max_uses,
+        expires_at,
+        min_name_len,
+        max_name_len,
+    } = msg;
+
+    // Ensure the caller is the admin
+    ensure_admin(deps.storage, &info.sender)?;
+
+    validate_voucher_code(&code)?;
+    if VOUCHERS.has(deps.storage, &code) {
+        return Err(ContractError::ValidationError {
+            reason: format!("Voucher {} already exists", code),
+        });
+    }
+
+    let voucher = Voucher {
+        discount,
+        max_uses,
+        uses: 0,
+        expires_at,
+        min_name_len,
+        max_name_len,
+    };
+    voucher.validate()?;
+    VOUCHERS.save(deps.storage, &code, &voucher)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "create_voucher"), attr("code", code)]))
+}
@@ -1,10 +1,54 @@
+pub mod batch_register;
+pub mod batch_update_metadata;
+pub mod claim_name;
+pub mod clear_metadata_fields;
+pub mod create_voucher;
 pub mod register;
+pub mod release_name;
+pub mod replace_metadata;
+pub mod revoke_voucher;
+pub mod set_address;
+pub mod set_content_hash;
+pub mod set_controller;
+pub mod set_primary_contract_name;
+pub mod set_primary_name;
+pub mod set_text_record;
+pub mod transfer;
+pub mod unset_address;
+pub mod unset_text_record;
+pub mod update_config;
+pub mod update_contract;
 pub mod update_metadata;
 
-use cosmwasm_std::{DepsMut, Env, MessageInfo};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, StdResult};
+
+use crate::{
+    error::ContractError,
+    query::render::CwWebsiteQueryMsg,
+    state::{is_local_address, PROBE_RENDER_TARGETS},
+};
 
 pub struct Context<'a> {
     pub deps: DepsMut<'a>,
     pub env: Env,
     pub info: MessageInfo,
 }
+
+/// If configured, check whether a target contract on this chain serves
+/// websites by sending it a render query for the root path. Targets on other
+/// chains can't be probed.
+pub fn probe_render_target(
+    deps: Deps,
+    env: &Env,
+    address: &str,
+) -> Result<Option<bool>, ContractError> {
+    if !PROBE_RENDER_TARGETS.load(deps.storage)? || !is_local_address(env, address) {
+        return Ok(None);
+    }
+    let render_msg = CwWebsiteQueryMsg::Render {
+        path: "/".to_string(),
+        context: None,
+    };
+    let response: StdResult<String> = deps.querier.query_wasm_smart(address, &render_msg);
+    Ok(Some(response.is_ok()))
+}
@@ -2,71 +2,208 @@ use crate::{
     error::ContractError,
     models::NameRecord,
     msg::RegisterMsg,
-    state::{FEE_RECIPIENT, NAME_METADATA, NAME_RECORDS, PRICE},
+    name::Name,
+    state::{
+        ensure_name_available, ensure_target_admin, init_primary_contract_name, update_keyword_index,
+        validate_contract_target, FEE_RECIPIENT, GIFTING_POLICY, NAME_METADATA, NAME_RECORDS, PRICE, REFERRAL_EARNINGS,
+        REFERRAL_SHARE, RELEASED_NAMES, VOUCHERS,
+    },
     token::TokenAmount,
-    utils::is_bech32_address,
 };
-use cosmwasm_std::{attr, Response};
+use cosmwasm_std::{attr, Addr, Api, Attribute, Coin, DepsMut, Env, Response, StdResult, Storage, SubMsg, Uint128};
 
-use super::Context;
+use super::{probe_render_target, Context};
 
 pub fn exec_register(
     ctx: Context,
     msg: RegisterMsg,
 ) -> Result<Response, ContractError> {
-    let Context { deps, info, env } = ctx;
+    let Context { mut deps, info, env } = ctx;
 
+    let (attrs, fee) = register_name(deps.branch(), &env, &info.sender, msg)?;
+
+    // Ensure user has sent payment and add transfer submsgs to send the fee
+    let transfers = collect_fees(deps.storage, &info.funds, vec![fee])?;
+
+    Ok(Response::new()
+        .add_submessages(transfers)
+        .add_attribute("action", "register")
+        .add_attributes(attrs))
+}
+
+/// Fee owed for registering a name after any voucher discount, along with the
+/// referrer's share of it
+pub struct RegistrationFee {
+    pub amount: Uint128,
+    pub referral: Option<(Addr, Uint128)>,
+}
+
+/// Ensure the sender paid exactly the combined registration fees and build
+/// the transfers paying each referrer their share and the rest to the fee
+/// recipient. When vouchers made the names free nothing is sent, and no
+/// funds may be attached as they would be stuck in the contract.
+pub fn collect_fees(
+    storage: &dyn Storage,
+    funds: &Vec<Coin>,
+    fees: Vec<RegistrationFee>,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let TokenAmount { token, .. } = PRICE.load(storage)?;
+    let total: Uint128 = fees.iter().map(|fee| fee.amount).sum();
+
+    if total.is_zero() {
+        if !funds.is_empty() {
+            return Err(ContractError::ValidationError {
+                reason: "No payment is due, don't attach funds".to_string(),
+            });
+        }
+        return Ok(vec![]);
+    }
+    if token.find_in_funds(funds, Some(total)).is_none() {
+        return Err(ContractError::InsufficientFunds {
+            exp_amount: total.into(),
+        });
+    }
+
+    let mut transfers = vec![];
+    let mut platform_fee = total;
+    for (referrer, share) in fees.into_iter().filter_map(|fee| fee.referral) {
+        platform_fee -= share;
+        transfers.push(token.transfer(&referrer, share)?);
+    }
+    if !platform_fee.is_zero() {
+        transfers.insert(0, token.transfer(&FEE_RECIPIENT.load(storage)?, platform_fee)?);
+    }
+    Ok(transfers)
+}
+
+/// Validate and save a new name record with its metadata, returning the
+/// attributes describing it and the fee owed for it. Payment is left to the
+/// caller.
+pub fn register_name(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    msg: RegisterMsg,
+) -> Result<(Vec<Attribute>, RegistrationFee), ContractError> {
     let RegisterMsg {
         owner,
         name,
         address: contract_addr,
+        content_hash,
         meta,
+        voucher,
+        referrer,
     } = msg;
 
-    let TokenAmount { token, amount: price } = PRICE.load(deps.storage)?;
+    // Ensure the name is valid and not registered yet
+    ensure_name_available(deps.storage, env.block.time, &name)?;
+    RELEASED_NAMES.remove(deps.storage, &name);
 
-    let cannonical_name = name.to_ascii_lowercase();
+    // Ensure the sender may register the name for someone else
+    let owner = deps.api.addr_validate(owner.as_str())?;
+    if owner != *sender {
+        GIFTING_POLICY.load(deps.storage)?.ensure_allowed(sender)?;
+    }
 
-    // Ensure user has sent payment
-    if token.find_in_funds(&info.funds, Some(price)).is_none() {
-        return Err(ContractError::InsufficientFunds {
-            exp_amount: price.into(),
-        });
+    // Ensure the address is a contract address on this or an allowed chain
+    if let Some(contract_addr) = &contract_addr {
+        validate_contract_target(deps.as_ref(), env, contract_addr)?;
+        ensure_target_admin(deps.as_ref(), env, sender, contract_addr)?;
     }
 
-    // Ensure the address string is a valid bech32 address
-    if !is_bech32_address(&contract_addr) {
-        return Err(ContractError::ValidationError {
-            reason: format!("{} is not a valid bech32 address", &contract_addr),
-        });
+    // Record whether the target contract can render websites
+    let render_capable = match &contract_addr {
+        Some(contract_addr) => probe_render_target(deps.as_ref(), env, contract_addr)?,
+        None => None,
+    };
+
+    // Ensure the content hash is well-formed
+    if let Some(content_hash) = &content_hash {
+        content_hash.validate()?;
     }
 
-    // Add transfer submsg to response to send platform fee
-    let resp = Response::new().add_submessage(token.transfer(&FEE_RECIPIENT.load(deps.storage)?, price)?);
+    // Create the name record
+    let record = NameRecord {
+        contract: contract_addr.to_owned(),
+        content_hash,
+        created_at: env.block.time,
+        render_capable,
+        owner: owner.clone(),
+        registrant: Some(sender.clone()),
+        controller: None,
+    };
+    record.ensure_target()?;
+    NAME_RECORDS.save(deps.storage, &name, &record)?;
 
-    // Create a name record or error out if already exists
-    NAME_RECORDS.update(
-        deps.storage,
-        &cannonical_name,
-        |maybe_record| -> Result<_, ContractError> {
-            if maybe_record.is_some() {
-                return Err(ContractError::NameExists { name });
-            }
-            Ok(NameRecord {
-                contract: contract_addr.to_owned(),
-                created_at: env.block.time,
-                owner: deps.api.addr_validate(&owner.as_str())?,
-            })
-        },
-    )?;
+    // Use the name for reverse lookups if it's the contract's first name
+    if let Some(contract_addr) = &contract_addr {
+        init_primary_contract_name(deps.storage, contract_addr, &name)?;
+    }
 
     // Save or init empty metadata for the NameRecord
-    NAME_METADATA.save(deps.storage, &cannonical_name, &meta.unwrap_or_default())?;
+    let meta = meta.unwrap_or_default();
+    meta.validate()?;
+    NAME_METADATA.save(deps.storage, &name, &meta)?;
+    update_keyword_index(deps.storage, &name, None, meta.keywords.as_ref())?;
 
-    Ok(resp.add_attributes(vec![
-        attr("action", "register"),
-        attr("name", cannonical_name),
-        attr("contract", contract_addr),
+    // Apply the voucher's discount and credit the referrer's share of the fee
+    let fee = charge_fee(deps.storage, deps.api, env, sender, &name, voucher, referrer)?;
+
+    let attrs = vec![
+        attr("name", name),
+        attr("contract", contract_addr.unwrap_or_default()),
         attr("owner", owner.to_string()),
-    ]))
+        attr("registrant", sender.to_string()),
+        attr("fee", fee.amount.to_string()),
+    ];
+    Ok((attrs, fee))
+}
+
+/// Work out the fee for a name, redeeming the voucher if one was given and
+/// adding the referrer's share to their earnings
+fn charge_fee(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    sender: &Addr,
+    name: &Name,
+    voucher: Option<String>,
+    referrer: Option<Addr>,
+) -> Result<RegistrationFee, ContractError> {
+    let TokenAmount { token, mut amount } = PRICE.load(storage)?;
+
+    if let Some(code) = voucher {
+        let mut voucher = VOUCHERS
+            .may_load(storage, &code)?
+            .ok_or_else(|| ContractError::NotFound {
+                reason: format!("Voucher {} does not exist", code),
+            })?;
+        voucher.ensure_redeemable(env.block.time, name)?;
+        voucher.uses += 1;
+        VOUCHERS.save(storage, &code, &voucher)?;
+        amount = voucher.discount.apply(amount);
+    }
+
+    let referral = match referrer {
+        Some(referrer) => {
+            let referrer = api.addr_validate(referrer.as_str())?;
+            if referrer == *sender {
+                return Err(ContractError::ValidationError {
+                    reason: "You may not refer yourself".to_string(),
+                });
+            }
+            let share = amount.multiply_ratio(REFERRAL_SHARE.load(storage)? as u128, 100u128);
+            if share.is_zero() {
+                None
+            } else {
+                REFERRAL_EARNINGS.update(storage, (&referrer, &token.to_key()), |earnings| -> StdResult<_> {
+                    Ok(earnings.unwrap_or_default() + share)
+                })?;
+                Some((referrer, share))
+            }
+        },
+        None => None,
+    };
+
+    Ok(RegistrationFee { amount, referral })
 }
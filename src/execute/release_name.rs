@@ -0,0 +1,62 @@
+use crate::{
+    error::ContractError,
+    msg::ReleaseNameMsg,
+    state::{
+        clear_name_records, release_primary_contract_name, update_keyword_index, NAME_METADATA, NAME_RECORDS,
+        PRIMARY_NAMES, RELEASED_NAMES, RELEASE_COOLDOWN,
+    },
+};
+use cosmwasm_std::{attr, Addr, Response};
+
+use super::Context;
+
+/// Give up a name, deleting its record, metadata and records. Only the owner
+/// may do this. The name can't be registered again until the configured
+/// cooldown has passed. Names don't expire, so no part of the fee is
+/// refunded.
+pub fn exec_release_name(
+    ctx: Context,
+    msg: ReleaseNameMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, env } = ctx;
+
+    let ReleaseNameMsg { name } = msg;
+
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    NAME_RECORDS.remove(deps.storage, &name)?;
+
+    // Drop the metadata along with its keyword index entries
+    if let Some(meta) = NAME_METADATA.may_load(deps.storage, &name)? {
+        update_keyword_index(deps.storage, &name, meta.keywords.as_ref(), None)?;
+    }
+    NAME_METADATA.remove(deps.storage, &name);
+
+    // Drop the text and address records
+    clear_name_records(deps.storage, &name)?;
+
+    // Drop reverse lookups pointing at the name
+    if let Some(contract_addr) = &record.contract {
+        release_primary_contract_name(deps.storage, contract_addr, &name)?;
+    }
+    let mut addresses = vec![record.owner.clone()];
+    addresses.extend(record.contract.as_ref().map(Addr::unchecked));
+    for address in addresses {
+        if PRIMARY_NAMES.may_load(deps.storage, &address)?.as_ref() == Some(&name) {
+            PRIMARY_NAMES.remove(deps.storage, &address);
+        }
+    }
+
+    // Hold the name back from registration for the cooldown
+    let available_at = env.block.time.plus_seconds(RELEASE_COOLDOWN.load(deps.storage)?);
+    RELEASED_NAMES.save(deps.storage, &name, &available_at)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "release_name"),
+        attr("name", name),
+        attr("available_at", available_at.seconds().to_string()),
+    ]))
+}
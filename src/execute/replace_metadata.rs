@@ -0,0 +1,19 @@
+use crate::{error::ContractError, msg::UpdateMetadataMsg};
+use cosmwasm_std::Response;
+
+use super::{update_metadata::update_name_metadata, Context};
+
+/// Replace a name's metadata entirely, clearing every field not set in the
+/// new metadata. Only the controller may do this.
+pub fn exec_replace_metadata(
+    ctx: Context,
+    msg: UpdateMetadataMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let UpdateMetadataMsg { name, meta } = msg;
+
+    update_name_metadata(deps, &info.sender, &name, |prev_meta| *prev_meta = meta)?;
+
+    Ok(Response::new().add_attribute("action", "replace_metadata"))
+}
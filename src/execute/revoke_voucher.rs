@@ -0,0 +1,31 @@
+use crate::{
+    error::ContractError,
+    msg::RevokeVoucherMsg,
+    state::{ensure_admin, VOUCHERS},
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Delete a voucher so it can't be redeemed anymore. Only the admin may do
+/// this.
+pub fn exec_revoke_voucher(
+    ctx: Context,
+    msg: RevokeVoucherMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let RevokeVoucherMsg { code } = msg;
+
+    // Ensure the caller is the admin
+    ensure_admin(deps.storage, &info.sender)?;
+
+    if !VOUCHERS.has(deps.storage, &code) {
+        return Err(ContractError::NotFound {
+            reason: format!("Voucher {} does not exist", code),
+        });
+    }
+    VOUCHERS.remove(deps.storage, &code);
+
+    Ok(Response::new().add_attributes(vec![attr("action", "revoke_voucher"), attr("code", code)]))
+}
@@ -0,0 +1,52 @@
+use crate::{
+    error::ContractError,
+    models::AddressRecord,
+    msg::SetAddressMsg,
+    state::{ADDRESS_RECORDS, NAME_RECORDS},
+};
+use cosmwasm_std::{attr, Order, Response};
+
+use super::Context;
+
+/// Set the address a name resolves to on a given chain, so that the name can
+/// be used as a payment handle across chains. Only the owner may do this.
+pub fn exec_set_address(
+    ctx: Context,
+    msg: SetAddressMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let SetAddressMsg { name, chain, address } = msg;
+
+    let address_record = AddressRecord { chain, address };
+    let address = address_record.validate()?;
+    let chain_key = address_record.chain.to_key();
+
+    // Ensure the name record exists
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    // Ensure adding a new chain doesn't exceed the max number of address records
+    if !ADDRESS_RECORDS.has(deps.storage, (&name, &chain_key))
+        && ADDRESS_RECORDS
+            .prefix(&name)
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .count()
+            >= AddressRecord::MAX_RECORDS
+    {
+        return Err(ContractError::ValidationError {
+            reason: format!("Names can have at most {} address records", AddressRecord::MAX_RECORDS),
+        });
+    }
+
+    ADDRESS_RECORDS.save(deps.storage, (&name, &chain_key), &address)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_address"),
+        attr("name", name),
+        attr("chain", chain_key),
+        attr("address", address),
+    ]))
+}
@@ -0,0 +1,32 @@
+use crate::{error::ContractError, msg::SetContentHashMsg, state::NAME_RECORDS};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Point a name at static content on IPFS, Arweave or a URL, or clear it if
+/// the name has a contract. Only the controller may do this.
+pub fn exec_set_content_hash(
+    ctx: Context,
+    msg: SetContentHashMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let SetContentHashMsg { name, content_hash } = msg;
+
+    let mut record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the controller of the name record
+    record.ensure_controller(&info.sender)?;
+
+    // Ensure the content hash is well-formed
+    if let Some(content_hash) = &content_hash {
+        content_hash.validate()?;
+    }
+
+    record.content_hash = content_hash;
+    record.ensure_target()?;
+
+    NAME_RECORDS.save(deps.storage, &name, &record)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "set_content_hash"), attr("name", name)]))
+}
@@ -0,0 +1,34 @@
+use crate::{error::ContractError, msg::SetControllerMsg, state::NAME_RECORDS};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Assign the address allowed to manage a name's target and metadata. Only
+/// the owner may do this. Passing no controller hands control back to the
+/// owner.
+pub fn exec_set_controller(
+    ctx: Context,
+    msg: SetControllerMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let SetControllerMsg { name, controller } = msg;
+
+    let mut record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    record.controller = match controller {
+        Some(addr) => Some(deps.api.addr_validate(addr.as_str())?),
+        None => None,
+    };
+
+    NAME_RECORDS.save(deps.storage, &name, &record)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_controller"),
+        attr("name", name),
+        attr("controller", record.controller().to_string()),
+    ]))
+}
@@ -0,0 +1,46 @@
+use crate::{
+    error::ContractError,
+    msg::SetPrimaryContractNameMsg,
+    state::{is_contract_admin, CONTRACT_ADDR_2_NAME, NAME_RECORDS},
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Choose which of the names pointing at a contract is returned when looking
+/// the contract up by address. The target contract itself or its wasm admin
+/// may always do this, the owner of the name only while the contract has no
+/// primary name yet.
+pub fn exec_set_primary_contract_name(
+    ctx: Context,
+    msg: SetPrimaryContractNameMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, env } = ctx;
+
+    let SetPrimaryContractNameMsg { name } = msg;
+
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    let contract_addr = record.contract.clone().ok_or_else(|| ContractError::NotFound {
+        reason: format!("{} does not point at a contract", name),
+    })?;
+
+    // Ensure the caller is the target contract or its admin, or else the
+    // owner of the name record filling an empty slot
+    if info.sender != contract_addr && !is_contract_admin(deps.as_ref(), &env, &info.sender, &contract_addr) {
+        if CONTRACT_ADDR_2_NAME.has(deps.storage, &contract_addr) {
+            return Err(ContractError::NotAuthorized {
+                reason: "Only the contract or its admin can change its primary name".to_string(),
+            });
+        }
+        record.ensure_owner(&info.sender)?;
+    }
+
+    CONTRACT_ADDR_2_NAME.save(deps.storage, &contract_addr, &name)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_primary_contract_name"),
+        attr("name", name),
+        attr("contract", contract_addr),
+    ]))
+}
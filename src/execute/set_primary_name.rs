@@ -0,0 +1,47 @@
+use crate::{
+    error::ContractError,
+    msg::SetPrimaryNameMsg,
+    state::{is_owner_or_target, NAME_RECORDS, PRIMARY_NAMES},
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Choose the name displayed for the sender's address, or clear it. The
+/// sender must own the name or be what it points at.
+pub fn exec_set_primary_name(
+    ctx: Context,
+    msg: SetPrimaryNameMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let SetPrimaryNameMsg { name } = msg;
+
+    let name = match name {
+        Some(name) => name,
+        None => {
+            PRIMARY_NAMES.remove(deps.storage, &info.sender);
+            return Ok(Response::new().add_attributes(vec![
+                attr("action", "set_primary_name"),
+                attr("address", info.sender.to_string()),
+            ]));
+        },
+    };
+
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller owns the name or is its target
+    if !is_owner_or_target(deps.storage, &name, &record, &info.sender)? {
+        return Err(ContractError::NotAuthorized {
+            reason: "You are neither the owner nor the target of this name".to_string(),
+        });
+    }
+
+    PRIMARY_NAMES.save(deps.storage, &info.sender, &name)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_primary_name"),
+        attr("address", info.sender.to_string()),
+        attr("name", name),
+    ]))
+}
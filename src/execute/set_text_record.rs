@@ -0,0 +1,50 @@
+use crate::{
+    error::ContractError,
+    models::TextRecord,
+    msg::SetTextRecordMsg,
+    state::{NAME_RECORDS, TEXT_RECORDS},
+};
+use cosmwasm_std::{attr, Order, Response};
+
+use super::Context;
+
+/// Set a key/value text record (e.g. "twitter", "url", "avatar") on a name.
+/// Text records are managed by the owner of the name, not its controller.
+pub fn exec_set_text_record(
+    ctx: Context,
+    msg: SetTextRecordMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let SetTextRecordMsg { name, key, value } = msg;
+
+    let text_record = TextRecord { key, value };
+    text_record.validate()?;
+
+    // Ensure the name record exists
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    // Ensure adding a new key doesn't exceed the max number of text records
+    if !TEXT_RECORDS.has(deps.storage, (&name, &text_record.key))
+        && TEXT_RECORDS
+            .prefix(&name)
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .count()
+            >= TextRecord::MAX_RECORDS
+    {
+        return Err(ContractError::ValidationError {
+            reason: format!("Names can have at most {} text records", TextRecord::MAX_RECORDS),
+        });
+    }
+
+    TEXT_RECORDS.save(deps.storage, (&name, &text_record.key), &text_record.value)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_text_record"),
+        attr("name", name),
+        attr("key", text_record.key),
+    ]))
+}
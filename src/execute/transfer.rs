@@ -0,0 +1,31 @@
+use crate::{error::ContractError, msg::TransferMsg, state::NAME_RECORDS};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Transfer ownership of a name. Only the owner may do this. Any controller
+/// set by the previous owner is cleared.
+pub fn exec_transfer(
+    ctx: Context,
+    msg: TransferMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let TransferMsg { name, owner } = msg;
+
+    let mut record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    record.owner = deps.api.addr_validate(owner.as_str())?;
+    record.controller = None;
+
+    NAME_RECORDS.save(deps.storage, &name, &record)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "transfer"),
+        attr("name", name),
+        attr("owner", record.owner.to_string()),
+    ]))
+}
@@ -0,0 +1,34 @@
+use crate::{
+    error::ContractError,
+    msg::UnsetAddressMsg,
+    state::{ADDRESS_RECORDS, NAME_RECORDS},
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Remove a name's address record for a chain. Only the owner may do this.
+pub fn exec_unset_address(
+    ctx: Context,
+    msg: UnsetAddressMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let UnsetAddressMsg { name, chain } = msg;
+
+    let chain_key = chain.to_key();
+
+    // Ensure the name record exists
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    ADDRESS_RECORDS.remove(deps.storage, (&name, &chain_key));
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "unset_address"),
+        attr("name", name),
+        attr("chain", chain_key),
+    ]))
+}
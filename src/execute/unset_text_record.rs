@@ -0,0 +1,32 @@
+use crate::{
+    error::ContractError,
+    msg::UnsetTextRecordMsg,
+    state::{NAME_RECORDS, TEXT_RECORDS},
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Remove a text record from a name. Only the owner may do this.
+pub fn exec_unset_text_record(
+    ctx: Context,
+    msg: UnsetTextRecordMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let UnsetTextRecordMsg { name, key } = msg;
+
+    // Ensure the name record exists
+    let record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the owner of the name record
+    record.ensure_owner(&info.sender)?;
+
+    TEXT_RECORDS.remove(deps.storage, (&name, &key));
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "unset_text_record"),
+        attr("name", name),
+        attr("key", key),
+    ]))
+}
@@ -0,0 +1,61 @@
+use crate::{
+    error::ContractError,
+    msg::UpdateConfigMsg,
+    state::{
+        ensure_admin, ADMIN, ALLOWED_HRPS, GIFTING_POLICY, PROBE_RENDER_TARGETS, REFERRAL_SHARE, RELEASE_COOLDOWN,
+        REQUIRE_TARGET_ADMIN, VERIFY_CONTRACT_TARGETS,
+    },
+    utils::{normalize_hrps, validate_referral_share},
+};
+use cosmwasm_std::{attr, Response};
+
+use super::Context;
+
+/// Update the contract-wide settings. Only the admin may do this.
+pub fn exec_update_config(
+    ctx: Context,
+    msg: UpdateConfigMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, .. } = ctx;
+
+    let UpdateConfigMsg {
+        admin,
+        allowed_hrps,
+        verify_contract_targets,
+        probe_render_targets,
+        require_target_admin,
+        gifting,
+        release_cooldown,
+        referral_share,
+    } = msg;
+
+    // Ensure the caller is the admin
+    ensure_admin(deps.storage, &info.sender)?;
+
+    if let Some(admin) = admin {
+        ADMIN.save(deps.storage, &deps.api.addr_validate(admin.as_str())?)?;
+    }
+    if let Some(allowed_hrps) = allowed_hrps {
+        ALLOWED_HRPS.save(deps.storage, &normalize_hrps(allowed_hrps)?)?;
+    }
+    if let Some(verify_contract_targets) = verify_contract_targets {
+        VERIFY_CONTRACT_TARGETS.save(deps.storage, &verify_contract_targets)?;
+    }
+    if let Some(probe_render_targets) = probe_render_targets {
+        PROBE_RENDER_TARGETS.save(deps.storage, &probe_render_targets)?;
+    }
+    if let Some(require_target_admin) = require_target_admin {
+        REQUIRE_TARGET_ADMIN.save(deps.storage, &require_target_admin)?;
+    }
+    if let Some(gifting) = gifting {
+        GIFTING_POLICY.save(deps.storage, &gifting.validate(deps.api)?)?;
+    }
+    if let Some(release_cooldown) = release_cooldown {
+        RELEASE_COOLDOWN.save(deps.storage, &release_cooldown)?;
+    }
+    if let Some(referral_share) = referral_share {
+        REFERRAL_SHARE.save(deps.storage, &validate_referral_share(referral_share)?)?;
+    }
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_config")]))
+}
@@ -0,0 +1,62 @@
+use crate::{
+    error::ContractError,
+    msg::UpdateContractMsg,
+    state::{
+        ensure_target_admin, init_primary_contract_name, release_primary_contract_name, validate_contract_target,
+        NAME_RECORDS,
+    },
+};
+use cosmwasm_std::{attr, Response};
+
+use super::{probe_render_target, Context};
+
+/// Point a name at a different contract, or at none if it has a content hash.
+/// Only the controller may do this.
+pub fn exec_update_contract(
+    ctx: Context,
+    msg: UpdateContractMsg,
+) -> Result<Response, ContractError> {
+    let Context { deps, info, env } = ctx;
+
+    let UpdateContractMsg {
+        name,
+        address: contract_addr,
+    } = msg;
+
+    let mut record = NAME_RECORDS.load(deps.storage, &name)?;
+
+    // Ensure the caller is the controller of the name record
+    record.ensure_controller(&info.sender)?;
+
+    // Ensure the address is a contract address on this or an allowed chain
+    if let Some(contract_addr) = &contract_addr {
+        validate_contract_target(deps.as_ref(), &env, contract_addr)?;
+        ensure_target_admin(deps.as_ref(), &env, &info.sender, contract_addr)?;
+    }
+
+    let prev_contract_addr = std::mem::replace(&mut record.contract, contract_addr.to_owned());
+    record.render_capable = match &contract_addr {
+        Some(contract_addr) => probe_render_target(deps.as_ref(), &env, contract_addr)?,
+        None => None,
+    };
+    record.ensure_target()?;
+
+    NAME_RECORDS.save(deps.storage, &name, &record)?;
+
+    // Move the reverse lookup entry over to the new contract, unless the
+    // name keeps pointing at the same one
+    if prev_contract_addr != contract_addr {
+        if let Some(prev_contract_addr) = &prev_contract_addr {
+            release_primary_contract_name(deps.storage, prev_contract_addr, &name)?;
+        }
+        if let Some(contract_addr) = &contract_addr {
+            init_primary_contract_name(deps.storage, contract_addr, &name)?;
+        }
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_contract"),
+        attr("name", name),
+        attr("contract", contract_addr.unwrap_or_default()),
+    ]))
+}
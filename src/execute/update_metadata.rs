@@ -1,9 +1,11 @@
 use crate::{
     error::ContractError,
+    models::NameMetadata,
     msg::UpdateMetadataMsg,
-    state::{NAME_METADATA, NAME_RECORDS},
+    name::Name,
+    state::{update_keyword_index, NAME_METADATA, NAME_RECORDS},
 };
-use cosmwasm_std::Response;
+use cosmwasm_std::{Addr, DepsMut, Response};
 
 use super::Context;
 
@@ -15,41 +17,57 @@ pub fn exec_update_metadata(
 
     let UpdateMetadataMsg { name, meta } = msg;
 
-    meta.validate()?;
+    update_name_metadata(deps, &info.sender, &name, |prev_meta| merge_metadata(prev_meta, meta))?;
+
+    Ok(Response::new().add_attribute("action", "update_metadata"))
+}
 
-    let cannonical_name = name.to_ascii_lowercase();
+/// Substitute the fields set in the new metadata, leaving the others unchanged
+pub fn merge_metadata(
+    prev_meta: &mut NameMetadata,
+    meta: NameMetadata,
+) {
+    prev_meta.title = meta.title.or(prev_meta.title.take());
+    prev_meta.description = meta.description.or(prev_meta.description.take());
+    prev_meta.favicon = meta.favicon.or(prev_meta.favicon.take());
+    prev_meta.logo = meta.logo.or(prev_meta.logo.take());
+    prev_meta.keywords = meta.keywords.or(prev_meta.keywords.take());
+    prev_meta.social_links = meta.social_links.or(prev_meta.social_links.take());
+    prev_meta.theme_color = meta.theme_color.or(prev_meta.theme_color.take());
+    prev_meta.background_color = meta.background_color.or(prev_meta.background_color.take());
+    prev_meta.banner = meta.banner.or(prev_meta.banner.take());
+    prev_meta.locale = meta.locale.or(prev_meta.locale.take());
+    prev_meta.canonical_url = meta.canonical_url.or(prev_meta.canonical_url.take());
+}
 
+/// Apply an update to a name's metadata on behalf of its controller, then
+/// validate the result and keep the keyword index in sync
+pub fn update_name_metadata(
+    deps: DepsMut,
+    sender: &Addr,
+    name: &Name,
+    update: impl FnOnce(&mut NameMetadata),
+) -> Result<NameMetadata, ContractError> {
     // Ensure the name record exists
-    let record = NAME_RECORDS.load(deps.storage, &cannonical_name)?;
-
-    // Ensure the caller is the owner of the name record
-    if record.owner != info.sender {
-        return Err(ContractError::NotAuthorized {
-            reason: "You are not the owner of this name".to_string(),
-        });
-    }
-
-    // Update the metadata, if the record already exists substitute the new metadata fields
-    NAME_METADATA.update(
-        deps.storage,
-        &cannonical_name,
-        |maybe_meta| -> Result<_, ContractError> {
-            if maybe_meta.is_none() {
-                return Err(ContractError::NotFound {
-                    reason: format!("Name {cannonical_name} has no metadata!, please report to admins"),
-                });
-            }
-            let mut new_meta = maybe_meta.unwrap();
-            new_meta.title = meta.title.or(new_meta.title);
-            new_meta.description = meta.description.or(new_meta.description);
-            new_meta.favicon = meta.favicon.or(new_meta.favicon);
-            new_meta.logo = meta.logo.or(new_meta.logo);
-            new_meta.keywords = meta.keywords.or(new_meta.keywords);
-            Ok(new_meta)
-        },
-    )?;
-
-    // NAME_METADATA.save(deps.storage, &cannonical_name, &meta)?;
+    let record = NAME_RECORDS.load(deps.storage, name)?;
 
-    Ok(Response::new().add_attribute("action", "update_metadata"))
+    // Ensure the caller is the controller of the name record
+    record.ensure_controller(sender)?;
+
+    let mut meta = NAME_METADATA
+        .may_load(deps.storage, name)?
+        .ok_or_else(|| ContractError::NotFound {
+            reason: format!("Name {name} has no metadata!, please report to admins"),
+        })?;
+    let prev_keywords = meta.keywords.clone();
+
+    update(&mut meta);
+    meta.validate()?;
+
+    NAME_METADATA.save(deps.storage, name, &meta)?;
+
+    // Keep the keyword index in sync with the new keywords
+    update_keyword_index(deps.storage, name, prev_keywords.as_ref(), meta.keywords.as_ref())?;
+
+    Ok(meta)
 }
@@ -5,6 +5,7 @@ pub mod error;
 pub mod execute;
 pub mod models;
 pub mod msg;
+pub mod name;
 #[cfg(not(feature = "library"))]
 pub mod query;
 pub mod state;
@@ -1,17 +1,86 @@
 use super::query::ReadonlyContext;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp};
+use cosmwasm_std::{Addr, Api, Binary, StdResult, Timestamp, Uint128};
 
-use crate::{error::ContractError, state::NAME_METADATA};
+use crate::{
+    error::ContractError,
+    name::Name,
+    state::NAME_METADATA,
+    utils::{bech32_data_len, bech32_prefix, validate_asset_url, validate_svg, ACCOUNT_ADDR_LEN, CONTRACT_ADDR_LEN},
+};
 
 #[cw_serde]
-pub struct Config {}
+pub struct Config {
+    /// None only if a migrated contract has no admin yet
+    pub admin: Option<Addr>,
+    /// Network prefixes of the other chains names may point at
+    pub allowed_hrps: Vec<String>,
+    /// Whether targets on this chain are checked to be existing contracts
+    pub verify_contract_targets: bool,
+    /// Whether targets on this chain are probed with a render query
+    pub probe_render_targets: bool,
+    /// Whether only the admin or creator of a target contract on this chain
+    /// may point names at it
+    pub require_target_admin: bool,
+    pub gifting: GiftingPolicy,
+    /// Seconds before a released name can be registered again
+    pub release_cooldown: u64,
+    /// Percentage of the registration fee paid to the referrer
+    pub referral_share: u8,
+}
+
+/// Who may register names owned by someone else
+#[cw_serde]
+#[derive(Default)]
+pub enum GiftingPolicy {
+    /// Anyone may register names for others
+    #[default]
+    Anyone,
+    /// Only the listed sponsors, e.g. an onboarding service, may
+    Sponsors(Vec<Addr>),
+    /// Names can only be registered by their owner
+    Disabled,
+}
+
+/// Price reduction granted by a voucher
+#[cw_serde]
+pub enum Discount {
+    /// Percentage taken off the price, from 1 to 100
+    Percent(u8),
+    /// Amount taken off the price
+    Fixed(Uint128),
+}
+
+/// Discount code created by the admin, redeemable when registering names
+#[cw_serde]
+pub struct Voucher {
+    pub discount: Discount,
+    /// Number of registrations the voucher can be redeemed for
+    pub max_uses: u32,
+    pub uses: u32,
+    pub expires_at: Option<Timestamp>,
+    /// Shortest and longest names the voucher applies to
+    pub min_name_len: Option<u8>,
+    pub max_name_len: Option<u8>,
+}
 
 #[cw_serde]
 pub struct NameRecord {
     pub owner: Addr,
-    pub contract: String,
+    /// Who manages what the name points at and shows: its target contract or
+    /// content hash and its metadata. Falls back to the owner if None. Text
+    /// and address records identify the owner, e.g. as a payment handle, so
+    /// only the owner manages them, like transfers and releases.
+    pub controller: Option<Addr>,
+    pub contract: Option<String>,
+    pub content_hash: Option<ContentHash>,
     pub created_at: Timestamp,
+    /// Who paid for the registration, None for names registered before this
+    /// was recorded
+    pub registrant: Option<Addr>,
+    /// Whether the target contract answered a render query when it was set,
+    /// None if it wasn't probed
+    pub render_capable: Option<bool>,
 }
 
 #[cw_serde]
@@ -22,20 +91,108 @@ pub struct NameMetadata {
     pub favicon: Option<ImageAsset>,
     pub logo: Option<ImageAsset>,
     pub keywords: Option<Vec<String>>,
+    pub social_links: Option<Vec<SocialLink>>,
+    pub theme_color: Option<String>,
+    pub background_color: Option<String>,
+    pub banner: Option<ImageAsset>,
+    pub locale: Option<String>,
+    pub canonical_url: Option<String>,
+}
+
+#[cw_serde]
+pub enum MetadataField {
+    Title,
+    Description,
+    Favicon,
+    Logo,
+    Keywords,
+    SocialLinks,
+    ThemeColor,
+    BackgroundColor,
+    Banner,
+    Locale,
+    CanonicalUrl,
+}
+
+#[cw_serde]
+pub struct SocialLink {
+    /// Platform name, e.g. "twitter", "discord" or "github"
+    pub platform: String,
+    pub url: String,
 }
 
 #[cw_serde]
 pub enum ImageAsset {
     Svg(String),
     Url(String),
+    /// Raster image stored on-chain, e.g. a PNG favicon
+    DataUri {
+        mime_type: String,
+        data: Binary,
+    },
+}
+
+#[cw_serde]
+pub enum ImageAssetKind {
+    Favicon,
+    Logo,
+    Banner,
+}
+
+/// Off-chain content a name can point at instead of a render contract
+#[cw_serde]
+pub enum ContentHash {
+    /// IPFS CID (v0 "Qm..." or v1 base32 "b...")
+    Ipfs(String),
+    /// Arweave transaction id
+    Arweave(String),
+    /// Plain https URL
+    Url(String),
+}
+
+#[cw_serde]
+pub struct TextRecord {
+    pub key: String,
+    pub value: String,
+}
+
+/// Identifies the chain an address record belongs to
+#[cw_serde]
+pub enum Chain {
+    /// A Cosmos chain, identified by its bech32 address prefix (e.g. "juno")
+    Bech32(String),
+    /// A chain identified by its SLIP-44 coin type (e.g. 0 for Bitcoin)
+    Slip44(u32),
+    /// An EVM chain, identified by its chain id (e.g. 1 for Ethereum mainnet)
+    Evm(u64),
+}
+
+#[cw_serde]
+pub struct AddressRecord {
+    pub chain: Chain,
+    pub address: String,
+}
+
+/// Progress of a migration re-saving the name records in batches
+#[cw_serde]
+pub struct MigrationProgress {
+    /// Last name record migrated, None before the first batch
+    pub cursor: Option<Name>,
+    /// Whether the networks migrated names point at are added to the
+    /// allowed ones, for contracts migrated from before they were configured
+    pub collect_hrps: bool,
 }
 
 #[cw_serde]
 pub struct PublicNameRecord {
     pub owner: Addr,
-    pub cannonical_name: String,
-    pub contract: String,
+    pub controller: Addr,
+    pub cannonical_name: Name,
+    pub contract: Option<String>,
+    pub content_hash: Option<ContentHash>,
     pub created_at: Timestamp,
+    pub registrant: Option<Addr>,
+    pub render_capable: Option<bool>,
     pub meta: NameMetadata,
 }
 
@@ -45,6 +202,10 @@ impl NameMetadata {
     pub const MAX_DESCRIPTION_LEN: usize = 500;
     pub const MAX_KEYWORDS: usize = 10;
     pub const MAX_KEYWORD_LEN: usize = 50;
+    pub const MAX_SOCIAL_LINKS: usize = 10;
+    pub const MAX_PLATFORM_LEN: usize = 32;
+    pub const MAX_URL_LEN: usize = 256;
+    pub const MAX_LOCALE_LEN: usize = 35;
 
     pub fn validate(&self) -> Result<(), ContractError> {
         if let Some(title) = &self.title {
@@ -61,6 +222,9 @@ impl NameMetadata {
                 });
             }
         }
+        for asset in [&self.favicon, &self.logo, &self.banner].into_iter().flatten() {
+            asset.validate()?;
+        }
         if let Some(keywords) = &self.keywords {
             if keywords.len() > Self::MAX_KEYWORDS {
                 return Err(ContractError::ValidationError {
@@ -75,23 +239,423 @@ impl NameMetadata {
                 }
             }
         }
+        if let Some(social_links) = &self.social_links {
+            if social_links.len() > Self::MAX_SOCIAL_LINKS {
+                return Err(ContractError::ValidationError {
+                    reason: format!("Social links must be less than {} in length", Self::MAX_SOCIAL_LINKS),
+                });
+            }
+            for SocialLink { platform, url } in social_links {
+                if platform.is_empty() || platform.len() > Self::MAX_PLATFORM_LEN {
+                    return Err(ContractError::ValidationError {
+                        reason: format!(
+                            "Social platform must be between 1 and {} characters",
+                            Self::MAX_PLATFORM_LEN
+                        ),
+                    });
+                }
+                Self::validate_url("Social link", url)?;
+            }
+        }
+        for (field, color) in [
+            ("Theme color", &self.theme_color),
+            ("Background color", &self.background_color),
+        ] {
+            if let Some(color) = color {
+                let is_hex = color
+                    .strip_prefix('#')
+                    .map(|hex| [3, 6, 8].contains(&hex.len()) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                    .unwrap_or(false);
+                if !is_hex {
+                    return Err(ContractError::ValidationError {
+                        reason: format!("{} must be a hex color like #1a2b3c", field),
+                    });
+                }
+            }
+        }
+        if let Some(locale) = &self.locale {
+            // BCP 47 style tag, e.g. "en" or "pt-BR"
+            let mut subtags = locale.split('-');
+            let is_valid = locale.len() <= Self::MAX_LOCALE_LEN
+                && subtags
+                    .next()
+                    .map(|lang| (2..=3).contains(&lang.len()) && lang.chars().all(|c| c.is_ascii_alphabetic()))
+                    .unwrap_or(false)
+                && subtags.all(|tag| (1..=8).contains(&tag.len()) && tag.chars().all(|c| c.is_ascii_alphanumeric()));
+            if !is_valid {
+                return Err(ContractError::ValidationError {
+                    reason: format!("{} is not a valid locale", locale),
+                });
+            }
+        }
+        if let Some(canonical_url) = &self.canonical_url {
+            Self::validate_url("Canonical URL", canonical_url)?;
+        }
+        Ok(())
+    }
+
+    /// Reset a single field back to unset
+    pub fn clear(
+        &mut self,
+        field: &MetadataField,
+    ) {
+        match field {
+            MetadataField::Title => self.title = None,
+            MetadataField::Description => self.description = None,
+            MetadataField::Favicon => self.favicon = None,
+            MetadataField::Logo => self.logo = None,
+            MetadataField::Keywords => self.keywords = None,
+            MetadataField::SocialLinks => self.social_links = None,
+            MetadataField::ThemeColor => self.theme_color = None,
+            MetadataField::BackgroundColor => self.background_color = None,
+            MetadataField::Banner => self.banner = None,
+            MetadataField::Locale => self.locale = None,
+            MetadataField::CanonicalUrl => self.canonical_url = None,
+        }
+    }
+
+    fn validate_url(
+        field: &str,
+        url: &str,
+    ) -> Result<(), ContractError> {
+        if url.len() > Self::MAX_URL_LEN
+            || !url.starts_with("https://")
+            || url.len() == "https://".len()
+            || url.chars().any(|c| c.is_whitespace() || c.is_control())
+        {
+            return Err(ContractError::ValidationError {
+                reason: format!(
+                    "{} must be an https URL of at most {} characters",
+                    field,
+                    Self::MAX_URL_LEN
+                ),
+            });
+        }
         Ok(())
     }
 }
 
+impl ImageAsset {
+    pub const MAX_DATA_LEN: usize = 32 * 1024;
+    pub const MIME_TYPES: [&'static str; 6] = [
+        "image/png",
+        "image/jpeg",
+        "image/gif",
+        "image/webp",
+        "image/x-icon",
+        "image/vnd.microsoft.icon",
+    ];
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        match self {
+            Self::Svg(svg) => validate_svg(svg),
+            Self::Url(url) => validate_asset_url(url),
+            Self::DataUri { mime_type, data } => {
+                if !Self::MIME_TYPES.contains(&mime_type.as_str()) {
+                    return Err(ContractError::ValidationError {
+                        reason: format!("Image MIME type must be one of {}", Self::MIME_TYPES.join(", ")),
+                    });
+                }
+                if data.is_empty() || data.len() > Self::MAX_DATA_LEN {
+                    return Err(ContractError::ValidationError {
+                        reason: format!("Image data must be between 1 and {} bytes", Self::MAX_DATA_LEN),
+                    });
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl ContentHash {
+    pub const MAX_URL_LEN: usize = 512;
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        let is_valid = match self {
+            Self::Ipfs(cid) => {
+                if let Some(v0) = cid.strip_prefix("Qm") {
+                    v0.len() == 44 && v0.chars().all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c))
+                } else if let Some(v1) = cid.strip_prefix('b') {
+                    (50..=100).contains(&v1.len())
+                        && v1.chars().all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c))
+                } else {
+                    false
+                }
+            },
+            Self::Arweave(tx_id) => {
+                tx_id.len() == 43 && tx_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            },
+            Self::Url(url) => {
+                url.len() <= Self::MAX_URL_LEN
+                    && url.len() > "https://".len()
+                    && url.starts_with("https://")
+                    && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+            },
+        };
+        if !is_valid {
+            return Err(ContractError::ValidationError {
+                reason: "Invalid content hash".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TextRecord {
+    pub const MAX_KEY_LEN: usize = 64;
+    pub const MAX_VALUE_LEN: usize = 1024;
+    pub const MAX_RECORDS: usize = 32;
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.key.is_empty() || self.key.len() > Self::MAX_KEY_LEN {
+            return Err(ContractError::ValidationError {
+                reason: format!("Text record key must be between 1 and {} characters", Self::MAX_KEY_LEN),
+            });
+        }
+        if self.value.len() > Self::MAX_VALUE_LEN {
+            return Err(ContractError::ValidationError {
+                reason: format!("Text record value must be less than {} characters", Self::MAX_VALUE_LEN),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Chain {
+    pub const SLIP44_ETH: u32 = 60;
+
+    pub fn to_key(&self) -> String {
+        match self {
+            Self::Bech32(hrp) => format!("b_{}", hrp.to_lowercase()),
+            Self::Slip44(coin_type) => format!("s_{}", coin_type),
+            Self::Evm(chain_id) => format!("e_{}", chain_id),
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        let value = &key[2..];
+        if key.starts_with("s_") {
+            Self::Slip44(value.parse().unwrap_or_default())
+        } else if key.starts_with("e_") {
+            Self::Evm(value.parse().unwrap_or_default())
+        } else {
+            Self::Bech32(value.to_string())
+        }
+    }
+}
+
+impl AddressRecord {
+    pub const MAX_ADDRESS_LEN: usize = 128;
+    pub const MAX_RECORDS: usize = 32;
+
+    /// Validate the address against the format expected by its chain and
+    /// return it in normalized (lowercase where case-insensitive) form
+    pub fn validate(&self) -> Result<String, ContractError> {
+        let invalid = || ContractError::ValidationError {
+            reason: format!("Invalid address for chain {}", self.chain.to_key()),
+        };
+        match &self.chain {
+            Chain::Bech32(hrp) => {
+                if hrp.is_empty()
+                    || bech32_prefix(&self.address) != Some(hrp.to_lowercase())
+                    || !matches!(
+                        bech32_data_len(&self.address),
+                        Some(ACCOUNT_ADDR_LEN | CONTRACT_ADDR_LEN)
+                    )
+                {
+                    return Err(invalid());
+                }
+                Ok(self.address.to_lowercase())
+            },
+            Chain::Evm(_) | Chain::Slip44(Chain::SLIP44_ETH) => {
+                let hex = self.address.strip_prefix("0x").ok_or_else(invalid)?;
+                if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(invalid());
+                }
+                Ok(self.address.to_lowercase())
+            },
+            Chain::Slip44(_) => {
+                if self.address.is_empty()
+                    || self.address.len() > Self::MAX_ADDRESS_LEN
+                    || !self.address.chars().all(|c| c.is_ascii_alphanumeric())
+                {
+                    return Err(invalid());
+                }
+                Ok(self.address.clone())
+            },
+        }
+    }
+}
+
+impl GiftingPolicy {
+    /// Validate the sponsor addresses
+    pub fn validate(
+        self,
+        api: &dyn Api,
+    ) -> Result<Self, ContractError> {
+        Ok(match self {
+            Self::Sponsors(sponsors) => Self::Sponsors(
+                sponsors
+                    .iter()
+                    .map(|sponsor| api.addr_validate(sponsor.as_str()))
+                    .collect::<StdResult<Vec<_>>>()?,
+            ),
+            policy => policy,
+        })
+    }
+
+    /// Ensure the sender may register a name for someone else
+    pub fn ensure_allowed(
+        &self,
+        sender: &Addr,
+    ) -> Result<(), ContractError> {
+        let is_allowed = match self {
+            Self::Anyone => true,
+            Self::Sponsors(sponsors) => sponsors.contains(sender),
+            Self::Disabled => false,
+        };
+        if !is_allowed {
+            return Err(ContractError::NotAuthorized {
+                reason: "You may not register names for others".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Discount {
+    /// Ensure the discount takes something off the price
+    pub fn validate(&self) -> Result<(), ContractError> {
+        let is_valid = match self {
+            Self::Percent(percent) => (1..=100).contains(percent),
+            Self::Fixed(amount) => !amount.is_zero(),
+        };
+        if !is_valid {
+            return Err(ContractError::ValidationError {
+                reason: "Discount must be between 1 and 100 percent or a non-zero amount".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Get the discounted price
+    pub fn apply(
+        &self,
+        price: Uint128,
+    ) -> Uint128 {
+        match self {
+            Self::Percent(percent) => price.multiply_ratio(100 - *percent as u128, 100u128),
+            Self::Fixed(amount) => price.saturating_sub(*amount),
+        }
+    }
+}
+
+impl Voucher {
+    /// Ensure the voucher can be redeemed at least once and its name length
+    /// scope isn't empty
+    pub fn validate(&self) -> Result<(), ContractError> {
+        self.discount.validate()?;
+        if self.max_uses == 0 {
+            return Err(ContractError::ValidationError {
+                reason: "Voucher must have at least one use".to_string(),
+            });
+        }
+        if let (Some(min_len), Some(max_len)) = (self.min_name_len, self.max_name_len) {
+            if min_len > max_len {
+                return Err(ContractError::ValidationError {
+                    reason: "Voucher min name length exceeds its max name length".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensure the voucher has uses left, hasn't expired and applies to the name
+    pub fn ensure_redeemable(
+        &self,
+        now: Timestamp,
+        name: &Name,
+    ) -> Result<(), ContractError> {
+        let reason = if self.uses >= self.max_uses {
+            "Voucher has been used up"
+        } else if self.expires_at.is_some_and(|expires_at| now >= expires_at) {
+            "Voucher has expired"
+        } else if self
+            .min_name_len
+            .is_some_and(|min_len| name.as_str().len() < min_len as usize)
+            || self
+                .max_name_len
+                .is_some_and(|max_len| name.as_str().len() > max_len as usize)
+        {
+            "Voucher does not apply to names of this length"
+        } else {
+            return Ok(());
+        };
+        Err(ContractError::ValidationError {
+            reason: reason.to_string(),
+        })
+    }
+}
+
 impl NameRecord {
+    /// Address allowed to change the target contract and metadata. Falls back
+    /// to the owner when no separate controller has been set.
+    pub fn controller(&self) -> &Addr {
+        self.controller.as_ref().unwrap_or(&self.owner)
+    }
+
+    /// Ensure the name points at a contract or content hash
+    pub fn ensure_target(&self) -> Result<(), ContractError> {
+        if self.contract.is_none() && self.content_hash.is_none() {
+            return Err(ContractError::ValidationError {
+                reason: "Name must point at a contract or content hash".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensure the sender is the owner (registrant) of the name
+    pub fn ensure_owner(
+        &self,
+        sender: &Addr,
+    ) -> Result<(), ContractError> {
+        if self.owner != *sender {
+            return Err(ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensure the sender is the controller of the name
+    pub fn ensure_controller(
+        &self,
+        sender: &Addr,
+    ) -> Result<(), ContractError> {
+        if self.controller() != sender {
+            return Err(ContractError::NotAuthorized {
+                reason: "You are not the controller of this name".to_string(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn build_public_name_record(
         &self,
         ctx: &ReadonlyContext,
-        cannonical_name: String,
+        cannonical_name: Name,
     ) -> Result<PublicNameRecord, ContractError> {
         let ReadonlyContext { deps, .. } = ctx;
 
         let meta = NAME_METADATA.load(deps.storage, &cannonical_name)?;
         return Ok(PublicNameRecord {
             owner: self.owner.clone(),
+            controller: self.controller().clone(),
             contract: self.contract.clone(),
+            content_hash: self.content_hash.clone(),
             created_at: self.created_at,
+            registrant: self.registrant.clone(),
+            render_capable: self.render_capable,
             cannonical_name,
             meta,
         });
@@ -1,9 +1,13 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Order, Timestamp};
 use serde_json::Value;
 
 use crate::{
-    models::{Config, NameMetadata, PublicNameRecord},
+    models::{
+        AddressRecord, Chain, Config, ContentHash, Discount, GiftingPolicy, ImageAssetKind, MetadataField,
+        NameMetadata, PublicNameRecord, TextRecord, Voucher,
+    },
+    name::Name,
     token::TokenAmount,
 };
 
@@ -12,27 +16,188 @@ pub struct InstantiateMsg {
     pub price: TokenAmount,
     pub fee_recipient: Addr,
     pub max_name_len: u8,
+    #[serde(default)]
+    pub allowed_hrps: Vec<String>,
+    #[serde(default)]
+    pub verify_contract_targets: bool,
+    #[serde(default)]
+    pub probe_render_targets: bool,
+    #[serde(default)]
+    pub require_target_admin: bool,
+    #[serde(default)]
+    pub gifting: GiftingPolicy,
+    #[serde(default = "default_release_cooldown")]
+    pub release_cooldown: u64,
+    #[serde(default)]
+    pub referral_share: u8,
+}
+
+/// Cooldown of released names when none is configured, e.g. for contracts
+/// migrated from before names could be released
+pub const DEFAULT_RELEASE_COOLDOWN: u64 = 30 * 24 * 60 * 60;
+
+/// Name records migrated per call when no limit is given, which keeps the
+/// re-indexing well within the gas limit of a block
+pub const DEFAULT_MIGRATE_LIMIT: u32 = 100;
+
+fn default_release_cooldown() -> u64 {
+    DEFAULT_RELEASE_COOLDOWN
 }
 
 #[cw_serde]
 pub struct RegisterMsg {
     pub owner: Addr,
-    pub name: String,
-    pub address: String,
+    pub name: Name,
+    pub address: Option<String>,
+    pub content_hash: Option<ContentHash>,
     pub meta: Option<NameMetadata>,
+    pub voucher: Option<String>,
+    pub referrer: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct BatchRegisterMsg {
+    pub names: Vec<RegisterMsg>,
 }
 
 #[cw_serde]
 pub struct UpdateMetadataMsg {
-    pub name: String,
+    pub name: Name,
     pub meta: NameMetadata,
 }
 
+#[cw_serde]
+pub struct BatchUpdateMetadataMsg {
+    pub updates: Vec<UpdateMetadataMsg>,
+}
+
+#[cw_serde]
+pub struct ClearMetadataFieldsMsg {
+    pub name: Name,
+    pub fields: Vec<MetadataField>,
+}
+
+#[cw_serde]
+pub struct SetControllerMsg {
+    pub name: Name,
+    pub controller: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct UpdateContractMsg {
+    pub name: Name,
+    pub address: Option<String>,
+}
+
+#[cw_serde]
+pub struct SetContentHashMsg {
+    pub name: Name,
+    pub content_hash: Option<ContentHash>,
+}
+
+#[cw_serde]
+pub struct TransferMsg {
+    pub name: Name,
+    pub owner: Addr,
+}
+
+#[cw_serde]
+pub struct SetPrimaryContractNameMsg {
+    pub name: Name,
+}
+
+#[cw_serde]
+pub struct SetPrimaryNameMsg {
+    pub name: Option<Name>,
+}
+
+#[cw_serde]
+pub struct SetTextRecordMsg {
+    pub name: Name,
+    pub key: String,
+    pub value: String,
+}
+
+#[cw_serde]
+pub struct UnsetTextRecordMsg {
+    pub name: Name,
+    pub key: String,
+}
+
+#[cw_serde]
+pub struct SetAddressMsg {
+    pub name: Name,
+    pub chain: Chain,
+    pub address: String,
+}
+
+#[cw_serde]
+pub struct UnsetAddressMsg {
+    pub name: Name,
+    pub chain: Chain,
+}
+
+#[cw_serde]
+pub struct UpdateConfigMsg {
+    pub admin: Option<Addr>,
+    pub allowed_hrps: Option<Vec<String>>,
+    pub verify_contract_targets: Option<bool>,
+    pub probe_render_targets: Option<bool>,
+    pub require_target_admin: Option<bool>,
+    pub gifting: Option<GiftingPolicy>,
+    pub release_cooldown: Option<u64>,
+    pub referral_share: Option<u8>,
+}
+
+#[cw_serde]
+pub struct CreateVoucherMsg {
+    pub code: String,
+    pub discount: Discount,
+    pub max_uses: u32,
+    pub expires_at: Option<Timestamp>,
+    pub min_name_len: Option<u8>,
+    pub max_name_len: Option<u8>,
+}
+
+#[cw_serde]
+pub struct RevokeVoucherMsg {
+    pub code: String,
+}
+
+#[cw_serde]
+pub struct ReleaseNameMsg {
+    pub name: Name,
+}
+
+#[cw_serde]
+pub struct ClaimNameMsg {
+    pub name: Name,
+}
+
 #[cw_serde]
 #[derive(cw_orch::ExecuteFns)]
 pub enum ExecuteMsg {
     Register(RegisterMsg),
+    BatchRegister(BatchRegisterMsg),
     UpdateMetadata(UpdateMetadataMsg),
+    BatchUpdateMetadata(BatchUpdateMetadataMsg),
+    ReplaceMetadata(UpdateMetadataMsg),
+    ClearMetadataFields(ClearMetadataFieldsMsg),
+    UpdateContract(UpdateContractMsg),
+    SetContentHash(SetContentHashMsg),
+    SetController(SetControllerMsg),
+    Transfer(TransferMsg),
+    SetPrimaryContractName(SetPrimaryContractNameMsg),
+    SetPrimaryName(SetPrimaryNameMsg),
+    SetTextRecord(SetTextRecordMsg),
+    UnsetTextRecord(UnsetTextRecordMsg),
+    SetAddress(SetAddressMsg),
+    UnsetAddress(UnsetAddressMsg),
+    UpdateConfig(UpdateConfigMsg),
+    ClaimName(ClaimNameMsg),
+    ReleaseName(ReleaseNameMsg),
+    CreateVoucher(CreateVoucherMsg),
+    RevokeVoucher(RevokeVoucherMsg),
 }
 
 #[cw_serde]
@@ -42,11 +207,74 @@ pub struct RenderQueryMsg {
     pub context: Option<Value>,
 }
 
+/// Rendered HTML, or where to fetch the site from when the name has no
+/// render contract
+#[cw_serde]
+#[serde(untagged)]
+pub enum RenderQueryResponse {
+    Html(String),
+    ContentHash { content_hash: ContentHash },
+}
+
+#[cw_serde]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl From<SortOrder> for Order {
+    fn from(order: SortOrder) -> Self {
+        match order {
+            SortOrder::Ascending => Order::Ascending,
+            SortOrder::Descending => Order::Descending,
+        }
+    }
+}
+
+#[cw_serde]
+pub enum NameRecordsSortKey {
+    Name,
+    CreatedAt,
+}
+
 #[cw_serde]
+#[derive(Default)]
 pub struct NameRecordsQueryMsg {
     pub limit: u8,
-    pub cursor: Option<String>,
+    pub cursor: Option<Name>,
     pub network_prefix: Option<String>, // pub context: Option<Value>,
+    pub order: Option<SortOrder>,
+    pub sort_by: Option<NameRecordsSortKey>,
+    pub created_after: Option<Timestamp>,
+    pub created_before: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct NamesByOwnerQueryMsg {
+    pub owner: Addr,
+    pub limit: u8,
+    pub cursor: Option<Name>,
+}
+
+#[cw_serde]
+pub struct NamesByContractQueryMsg {
+    pub contract: String,
+    pub limit: u8,
+    pub cursor: Option<Name>,
+}
+
+#[cw_serde]
+pub struct NamesByKeywordQueryMsg {
+    pub keyword: String,
+    pub limit: u8,
+    pub cursor: Option<Name>,
+}
+
+#[cw_serde]
+pub struct SearchNamesQueryMsg {
+    pub query: String,
+    pub limit: u8,
+    pub cursor: Option<Name>,
 }
 
 #[cw_serde]
@@ -55,7 +283,7 @@ pub enum QueryMsg {
     #[returns(ConfigResponse)]
     Config {},
 
-    #[returns(String)]
+    #[returns(RenderQueryResponse)]
     Render(RenderQueryMsg),
 
     #[returns(PublicNameRecord)]
@@ -63,10 +291,67 @@ pub enum QueryMsg {
 
     #[returns(NameRecordsQueryResponse)]
     NameRecords(NameRecordsQueryMsg),
+
+    #[returns(NameRecordsQueryResponse)]
+    RecentNameRecords { limit: u8, cursor: Option<Name> },
+
+    #[returns(NameRecordsQueryResponse)]
+    NamesByOwner(NamesByOwnerQueryMsg),
+
+    #[returns(NameRecordsQueryResponse)]
+    NamesByContract(NamesByContractQueryMsg),
+
+    #[returns(NameRecordsQueryResponse)]
+    NamesByKeyword(NamesByKeywordQueryMsg),
+
+    #[returns(SearchNamesQueryResponse)]
+    SearchNames(SearchNamesQueryMsg),
+
+    #[returns(IsAvailableQueryResponse)]
+    IsAvailable { name: String },
+
+    #[returns(BatchNameRecordsQueryResponse)]
+    BatchNameRecords { names: Vec<String> },
+
+    #[returns(BatchResolveQueryResponse)]
+    BatchResolve { names_or_addresses: Vec<String> },
+
+    #[returns(Option<String>)]
+    TextRecord { name: Name, key: String },
+
+    #[returns(TextRecordsQueryResponse)]
+    TextRecords { name: Name },
+
+    #[returns(Option<String>)]
+    ResolveAddress { name: Name, chain: crate::models::Chain },
+
+    #[returns(AddressRecordsQueryResponse)]
+    AddressRecords { name: Name },
+
+    #[returns(Option<Name>)]
+    PrimaryName { address: Addr },
+
+    #[returns(ImageAssetQueryResponse)]
+    ImageAsset { name: Name, kind: ImageAssetKind },
+
+    #[returns(VoucherQueryResponse)]
+    Voucher { code: String },
+
+    #[returns(ReferralEarningsQueryResponse)]
+    ReferralEarnings { referrer: Addr },
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// Admin to set, e.g. for contracts instantiated before the admin role
+    /// existed
+    pub admin: Option<Addr>,
+    /// Max number of name records to migrate in this call, see
+    /// DEFAULT_MIGRATE_LIMIT. Until the "done" attribute is true, migrate
+    /// again to the same code to continue where the last call stopped.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
 
 #[cw_serde]
 pub struct ConfigResponse(pub Config);
@@ -74,5 +359,61 @@ pub struct ConfigResponse(pub Config);
 #[cw_serde]
 pub struct NameRecordsQueryResponse {
     pub name_records: Vec<PublicNameRecord>,
-    pub next_cursor: Option<String>,
+    pub next_cursor: Option<Name>,
+}
+
+#[cw_serde]
+pub struct SearchNamesQueryResponse {
+    pub name_records: Vec<PublicNameRecord>,
+    pub next_cursor: Option<Name>,
+    pub is_available: bool,
+}
+
+#[cw_serde]
+pub struct IsAvailableQueryResponse {
+    pub cannonical_name: String,
+    pub is_available: bool,
+    pub reason: Option<String>,
+    pub price: TokenAmount,
+}
+
+#[cw_serde]
+pub struct BatchNameRecordsQueryResponse {
+    pub name_records: Vec<Option<PublicNameRecord>>,
+}
+
+#[cw_serde]
+pub struct BatchResolveQueryResponse {
+    pub contracts: Vec<Option<String>>,
+}
+
+#[cw_serde]
+pub struct TextRecordsQueryResponse {
+    pub text_records: Vec<TextRecord>,
+}
+
+#[cw_serde]
+pub struct AddressRecordsQueryResponse {
+    pub address_records: Vec<AddressRecord>,
+}
+
+#[cw_serde]
+pub struct ImageAssetQueryResponse {
+    pub content_type: String,
+    pub data: Binary,
+}
+
+#[cw_serde]
+pub struct VoucherQueryResponse {
+    pub code: String,
+    pub voucher: Voucher,
+    pub uses_left: u32,
+    pub is_expired: bool,
+}
+
+/// Total registration fees paid out to a referrer, per token
+#[cw_serde]
+pub struct ReferralEarningsQueryResponse {
+    pub referrer: Addr,
+    pub earnings: Vec<TokenAmount>,
 }
@@ -0,0 +1,165 @@
+use std::{fmt, str::FromStr};
+
+use cosmwasm_schema::schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use cosmwasm_std::{StdError, StdResult};
+use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    error::ContractError,
+    utils::{normalize_name, validate_name},
+};
+
+/// A canonical name: the UTS-46 normalized, punycode-encoded label names are
+/// stored under. Names are normalized and validated when they are parsed.
+/// Deserialization is lenient so that names registered before names were
+/// validated can still be looked up, see `Name::parse_lenient`. This also
+/// applies to names read back from storage, which is safe because migrating
+/// moves every stored name that normalizes differently to its canonical key,
+/// failing on collisions. Registration checks that a name is valid,
+/// including the configurable max name length.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(into = "String")]
+pub struct Name(String);
+
+impl Name {
+    /// Max length of a DNS label
+    pub const MAX_LEN: u8 = 63;
+
+    /// Wrap a string already known to be canonical, e.g. a storage key
+    pub(crate) fn from_canonical(name: String) -> Self {
+        Self(name)
+    }
+
+    /// Parse a name, falling back to the plain lowercasing names were stored
+    /// with before they were normalized and validated if it isn't valid
+    pub fn parse_lenient(name: String) -> Self {
+        match Self::try_from(name.as_str()) {
+            Ok(cannonical_name) => cannonical_name,
+            Err(_) => Self(name.to_ascii_lowercase()),
+        }
+    }
+
+    /// Ensure the name is canonical and valid, as new names must be
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if Self::try_from(self.as_str())? != *self {
+            return Err(ContractError::ValidationError {
+                reason: format!("Name {} is not in canonical form", self.0),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for Name {
+    type Error = ContractError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let cannonical_name = normalize_name(&name)?;
+        validate_name(&cannonical_name, Self::MAX_LEN)?;
+        Ok(Self(cannonical_name))
+    }
+}
+
+impl TryFrom<&str> for Name {
+    type Error = ContractError;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        Self::try_from(name.to_owned())
+    }
+}
+
+impl FromStr for Name {
+    type Err = ContractError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::try_from(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::parse_lenient(String::deserialize(deserializer)?))
+    }
+}
+
+impl From<Name> for String {
+    fn from(name: Name) -> Self {
+        name.0
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<&str> for Name {
+    fn eq(
+        &self,
+        other: &&str,
+    ) -> bool {
+        self.0 == *other
+    }
+}
+
+impl JsonSchema for Name {
+    fn schema_name() -> String {
+        "Name".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
+impl<'a> PrimaryKey<'a> for Name {
+    type Prefix = ();
+    type SubPrefix = ();
+    type Suffix = Self;
+    type SuperSuffix = Self;
+
+    fn key(&self) -> Vec<Key<'_>> {
+        vec![Key::Ref(self.0.as_bytes())]
+    }
+}
+
+impl<'a> Prefixer<'a> for Name {
+    fn prefix(&self) -> Vec<Key<'_>> {
+        vec![Key::Ref(self.0.as_bytes())]
+    }
+}
+
+impl KeyDeserialize for Name {
+    type Output = Name;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        String::from_utf8(value).map(Self).map_err(StdError::invalid_utf8)
+    }
+}
+
+impl KeyDeserialize for &Name {
+    type Output = Name;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        Name::from_vec(value)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    error::ContractError,
+    models::{AddressRecord, Chain},
+    msg::AddressRecordsQueryResponse,
+    name::Name,
+    state::ADDRESS_RECORDS,
+};
+use cosmwasm_std::{Order, StdResult};
+
+use super::ReadonlyContext;
+
+/// Return all explicit address records of a name. The number of records per
+/// name is capped, so no pagination is needed.
+pub fn query_address_records(
+    ctx: ReadonlyContext,
+    name: Name,
+) -> Result<AddressRecordsQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    let address_records = ADDRESS_RECORDS
+        .prefix(&name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(chain_key, address)| AddressRecord {
+                chain: Chain::from_key(&chain_key),
+                address,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AddressRecordsQueryResponse { address_records })
+}
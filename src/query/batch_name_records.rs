@@ -0,0 +1,28 @@
+use crate::{error::ContractError, msg::BatchNameRecordsQueryResponse};
+
+use super::{name_record::query_name_record, ReadonlyContext};
+
+pub const MAX_BATCH_QUERY_SIZE: usize = 30;
+
+/// Return the NameRecord of each given name or contract address, in order
+pub fn query_batch_name_records(
+    ctx: ReadonlyContext,
+    names: Vec<String>,
+) -> Result<BatchNameRecordsQueryResponse, ContractError> {
+    if names.len() > MAX_BATCH_QUERY_SIZE {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_BATCH_QUERY_SIZE as u8,
+        });
+    }
+
+    let mut name_records = Vec::with_capacity(names.len());
+    for name in names {
+        let ctx = ReadonlyContext {
+            deps: ctx.deps,
+            env: ctx.env.clone(),
+        };
+        name_records.push(query_name_record(ctx, name)?);
+    }
+
+    Ok(BatchNameRecordsQueryResponse { name_records })
+}
@@ -0,0 +1,28 @@
+use crate::{error::ContractError, msg::BatchResolveQueryResponse, state::resolve_contract_address};
+
+use super::{batch_name_records::MAX_BATCH_QUERY_SIZE, ReadonlyContext};
+
+/// Resolve the contract address of each given name or address, in order
+pub fn query_batch_resolve(
+    ctx: ReadonlyContext,
+    names_or_addresses: Vec<String>,
+) -> Result<BatchResolveQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    if names_or_addresses.len() > MAX_BATCH_QUERY_SIZE {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_BATCH_QUERY_SIZE as u8,
+        });
+    }
+
+    let mut contracts = Vec::with_capacity(names_or_addresses.len());
+    for addr_or_name in names_or_addresses.iter() {
+        contracts.push(match resolve_contract_address(&deps, addr_or_name) {
+            Ok(contract_addr) => Some(contract_addr),
+            Err(ContractError::NotFound { .. }) => None,
+            Err(err) => return Err(err),
+        });
+    }
+
+    Ok(BatchResolveQueryResponse { contracts })
+}
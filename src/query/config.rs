@@ -1,8 +1,25 @@
-use crate::{error::ContractError, models::Config, msg::ConfigResponse};
+use crate::{
+    error::ContractError,
+    models::Config,
+    msg::ConfigResponse,
+    state::{
+        ADMIN, ALLOWED_HRPS, GIFTING_POLICY, PROBE_RENDER_TARGETS, REFERRAL_SHARE, RELEASE_COOLDOWN,
+        REQUIRE_TARGET_ADMIN, VERIFY_CONTRACT_TARGETS,
+    },
+};
 
 use super::ReadonlyContext;
 
 pub fn query_config(ctx: ReadonlyContext) -> Result<ConfigResponse, ContractError> {
-    let ReadonlyContext { .. } = ctx;
-    Ok(ConfigResponse(Config {}))
+    let ReadonlyContext { deps, .. } = ctx;
+    Ok(ConfigResponse(Config {
+        admin: ADMIN.may_load(deps.storage)?,
+        allowed_hrps: ALLOWED_HRPS.load(deps.storage)?,
+        verify_contract_targets: VERIFY_CONTRACT_TARGETS.load(deps.storage)?,
+        probe_render_targets: PROBE_RENDER_TARGETS.load(deps.storage)?,
+        require_target_admin: REQUIRE_TARGET_ADMIN.load(deps.storage)?,
+        gifting: GIFTING_POLICY.load(deps.storage)?,
+        release_cooldown: RELEASE_COOLDOWN.load(deps.storage)?,
+        referral_share: REFERRAL_SHARE.load(deps.storage)?,
+    }))
 }
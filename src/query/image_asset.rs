@@ -0,0 +1,47 @@
+use cosmwasm_std::Binary;
+
+use crate::{
+    error::ContractError,
+    models::{ImageAsset, ImageAssetKind},
+    msg::ImageAssetQueryResponse,
+    name::Name,
+    state::NAME_METADATA,
+};
+
+use super::ReadonlyContext;
+
+/// Return a name's favicon, logo or banner as raw bytes with its content
+/// type, so the gateway can serve it straight from contract state. Assets
+/// hosted at a URL are not stored on-chain and can't be returned.
+pub fn query_image_asset(
+    ctx: ReadonlyContext,
+    name: Name,
+    kind: ImageAssetKind,
+) -> Result<ImageAssetQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    let meta = NAME_METADATA.may_load(deps.storage, &name)?.unwrap_or_default();
+
+    let asset = match kind {
+        ImageAssetKind::Favicon => meta.favicon,
+        ImageAssetKind::Logo => meta.logo,
+        ImageAssetKind::Banner => meta.banner,
+    };
+
+    match asset {
+        Some(ImageAsset::Svg(svg)) => Ok(ImageAssetQueryResponse {
+            content_type: "image/svg+xml".to_string(),
+            data: Binary::from(svg.into_bytes()),
+        }),
+        Some(ImageAsset::DataUri { mime_type, data }) => Ok(ImageAssetQueryResponse {
+            content_type: mime_type,
+            data,
+        }),
+        Some(ImageAsset::Url(url)) => Err(ContractError::NotFound {
+            reason: format!("{:?} of {} is hosted at {}", kind, name, url),
+        }),
+        None => Err(ContractError::NotFound {
+            reason: format!("{} has no {:?}", name, kind),
+        }),
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    error::ContractError,
+    msg::IsAvailableQueryResponse,
+    name::Name,
+    state::{ensure_name_available, PRICE},
+};
+
+use super::ReadonlyContext;
+
+/// Check whether a name can be registered and at what price
+pub fn query_is_available(
+    ctx: ReadonlyContext,
+    name: String,
+) -> Result<IsAvailableQueryResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+
+    let (cannonical_name, result) = match Name::try_from(name.as_str()) {
+        Ok(cannonical_name) => {
+            let result = ensure_name_available(deps.storage, env.block.time, &cannonical_name);
+            (cannonical_name.into_string(), result)
+        },
+        Err(err) => (name.to_lowercase(), Err(err)),
+    };
+
+    let reason = match result {
+        Ok(()) => None,
+        Err(err @ (ContractError::ValidationError { .. } | ContractError::NameExists { .. })) => Some(err.to_string()),
+        Err(err) => return Err(err),
+    };
+
+    Ok(IsAvailableQueryResponse {
+        cannonical_name,
+        is_available: reason.is_none(),
+        reason,
+        price: PRICE.load(deps.storage)?,
+    })
+}
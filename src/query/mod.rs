@@ -1,7 +1,22 @@
+pub mod address_records;
+pub mod batch_name_records;
+pub mod batch_resolve;
 pub mod config;
+pub mod image_asset;
+pub mod is_available;
 pub mod name_record;
 pub mod name_records;
+pub mod names_by_contract;
+pub mod names_by_keyword;
+pub mod names_by_owner;
+pub mod primary_name;
+pub mod referral_earnings;
 pub mod render;
+pub mod resolve_address;
+pub mod search_names;
+pub mod text_record;
+pub mod text_records;
+pub mod voucher;
 
 use cosmwasm_std::{Deps, Env};
 
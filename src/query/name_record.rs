@@ -1,7 +1,8 @@
 use crate::{
     error::ContractError,
     models::PublicNameRecord,
-    state::{CONTRACT_ADDR_2_NAME, NAME_METADATA, NAME_RECORDS},
+    name::Name,
+    state::{CONTRACT_ADDR_2_NAME, NAME_RECORDS},
     utils::is_bech32_address,
 };
 
@@ -18,18 +19,11 @@ pub fn query_name_record(
     if let Some(cannonical_name) = if is_bech32_address(&contract) {
         CONTRACT_ADDR_2_NAME.may_load(deps.storage, &contract)?
     } else {
-        Some(contract.to_ascii_lowercase())
+        Some(Name::parse_lenient(contract))
     } {
         if let Some(record) = NAME_RECORDS.may_load(deps.storage, &cannonical_name)? {
             // Build and return public NameRecord
-            let meta = NAME_METADATA.load(deps.storage, &cannonical_name)?;
-            return Ok(Some(PublicNameRecord {
-                owner: record.owner,
-                contract: record.contract,
-                created_at: record.created_at,
-                cannonical_name,
-                meta,
-            }));
+            return Ok(Some(record.build_public_name_record(&ctx, cannonical_name)?));
         }
     }
 
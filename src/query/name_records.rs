@@ -1,12 +1,19 @@
-use std::marker::PhantomData;
-
 use super::ReadonlyContext;
-use crate::msg::{NameRecordsQueryMsg, NameRecordsQueryResponse};
-use crate::{error::ContractError, models::PublicNameRecord, state::NAME_RECORDS};
+use crate::models::NameRecord;
+use crate::msg::{NameRecordsQueryMsg, NameRecordsQueryResponse, NameRecordsSortKey, SortOrder};
+use crate::{
+    error::ContractError,
+    models::PublicNameRecord,
+    name::Name,
+    state::{NAME_RECORDS, UNINDEXED_KEY},
+};
+use cosmwasm_std::{Order, StdResult, Timestamp};
 use cw_storage_plus::Bound;
 
 pub const MAX_REQUEST_LIMIT: u8 = 30;
-/// Return x number of NameRecords
+/// Return x number of NameRecords, optionally only those whose contract lives
+/// on the network with the given bech32 prefix (e.g. "juno"). Records are
+/// sorted by name or, through the created_at index, by registration time.
 pub fn query_name_records(
     ctx: ReadonlyContext,
     msg: NameRecordsQueryMsg,
@@ -15,7 +22,11 @@ pub fn query_name_records(
     let NameRecordsQueryMsg {
         cursor,
         limit,
-        network_prefix: network_den,
+        network_prefix,
+        order,
+        sort_by,
+        created_after,
+        created_before,
     } = msg;
 
     if limit > MAX_REQUEST_LIMIT {
@@ -24,34 +35,83 @@ pub fn query_name_records(
         });
     }
 
-    let bounding_id: String;
-    let mut min_bound: Option<Bound<&String>> = None;
     // check if the cursor is not none and exists
+    let mut cursor_record: Option<(Name, NameRecord)> = None;
     if let Some(id) = cursor {
-        if NAME_RECORDS.may_load(deps.storage, &id)?.is_none() {
-            return Err(ContractError::NotFound {
-                reason: format!("Name {} not found", id.clone()),
-            });
+        match NAME_RECORDS.may_load(deps.storage, &id)? {
+            Some(record) => cursor_record = Some((id, record)),
+            None => {
+                return Err(ContractError::NotFound {
+                    reason: format!("Name {} not found", id.clone()),
+                });
+            },
         }
-        bounding_id = id.clone();
-        min_bound = Some(Bound::Exclusive((&bounding_id, PhantomData)));
     }
 
-    let max_bound = None;
+    let order: Order = order.unwrap_or(SortOrder::Ascending).into();
+    let network_prefix = network_prefix.map(|network| network.to_ascii_lowercase());
+    // Names without a contract aren't on any network
+    if network_prefix.as_deref() == Some(UNINDEXED_KEY) {
+        return Ok(Some(NameRecordsQueryResponse {
+            name_records: vec![],
+            next_cursor: None,
+        }));
+    }
 
-    let network = network_den.unwrap_or("".to_string());
+    let records: Box<dyn Iterator<Item = StdResult<(Name, NameRecord)>>> =
+        match sort_by.unwrap_or(NameRecordsSortKey::Name) {
+            NameRecordsSortKey::Name => {
+                if created_after.is_some() || created_before.is_some() {
+                    return Err(ContractError::ValidationError {
+                        reason: "created_after and created_before require sorting by created_at".to_string(),
+                    });
+                }
+                // Only iterate over the records of the requested network, if any
+                match network_prefix {
+                    Some(network) => {
+                        let cursor_name = cursor_record.map(|(name, _)| name);
+                        let (min, max) = match order {
+                            Order::Ascending => (cursor_name.map(Bound::exclusive), None),
+                            Order::Descending => (None, cursor_name.map(Bound::exclusive)),
+                        };
+                        NAME_RECORDS
+                            .idx
+                            .network
+                            .prefix(network)
+                            .range(deps.storage, min, max, order)
+                    },
+                    None => {
+                        let cursor_name = cursor_record.as_ref().map(|(name, _)| name);
+                        let (min, max) = match order {
+                            Order::Ascending => (cursor_name.map(Bound::exclusive), None),
+                            Order::Descending => (None, cursor_name.map(Bound::exclusive)),
+                        };
+                        NAME_RECORDS.range(deps.storage, min, max, order)
+                    },
+                }
+            },
+            NameRecordsSortKey::CreatedAt => {
+                let cursor_key = cursor_record
+                    .as_ref()
+                    .map(|(name, record)| (record.created_at.nanos(), name.clone()));
+                let (min, max) = created_at_bounds(order, cursor_key, created_after, created_before);
+                match network_prefix {
+                    Some(network) => {
+                        NAME_RECORDS
+                            .idx
+                            .network_created
+                            .sub_prefix(network)
+                            .range(deps.storage, min, max, order)
+                    },
+                    None => NAME_RECORDS.idx.created.range(deps.storage, min, max, order),
+                }
+            },
+        };
 
     let mut name_records: Vec<PublicNameRecord> = Vec::with_capacity(limit as usize);
 
     let mut next_cursor_id = None;
-    for record in NAME_RECORDS
-        .range(deps.storage, min_bound, max_bound, cosmwasm_std::Order::Ascending)
-        .filter(|item| {
-            let (_id, name_record_item) = item.as_ref().unwrap();
-            name_record_item.contract.starts_with(&network)
-        })
-        .take(limit as usize)
-    {
+    for record in records.take(limit as usize) {
         let (name, name_record) = record?;
         next_cursor_id = Some(name.clone());
         let public_name_record = name_record.build_public_name_record(&ctx, name)?;
@@ -62,3 +122,36 @@ pub fn query_name_records(
         next_cursor: next_cursor_id,
     }))
 }
+
+type CreatedAtBound<'a> = Option<Bound<'a, (u64, Name)>>;
+
+/// Build (created_at, name) index bounds from the cursor and the optional
+/// exclusive created_after/created_before filters, keeping the tighter bound.
+fn created_at_bounds<'a>(
+    order: Order,
+    cursor_key: Option<(u64, Name)>,
+    created_after: Option<Timestamp>,
+    created_before: Option<Timestamp>,
+) -> (CreatedAtBound<'a>, CreatedAtBound<'a>) {
+    // No name sorts before the empty string, so these keys cover whole timestamps
+    let after_key = created_after.map(|t| (t.nanos().saturating_add(1), Name::from_canonical(String::new())));
+    let before_key = created_before.map(|t| (t.nanos(), Name::from_canonical(String::new())));
+    match order {
+        Order::Ascending => {
+            let min = match (cursor_key, after_key) {
+                (Some(c), Some(a)) if c < a => Some(Bound::inclusive(a)),
+                (Some(c), _) => Some(Bound::exclusive(c)),
+                (None, a) => a.map(Bound::inclusive),
+            };
+            (min, before_key.map(Bound::exclusive))
+        },
+        Order::Descending => {
+            let max = match (cursor_key, before_key) {
+                (Some(c), Some(b)) if c > b => Some(Bound::exclusive(b)),
+                (Some(c), _) => Some(Bound::exclusive(c)),
+                (None, b) => b.map(Bound::exclusive),
+            };
+            (after_key.map(Bound::inclusive), max)
+        },
+    }
+}
@@ -0,0 +1,59 @@
+use super::name_records::MAX_REQUEST_LIMIT;
+use super::ReadonlyContext;
+use crate::msg::{NameRecordsQueryResponse, NamesByContractQueryMsg};
+use crate::{
+    error::ContractError,
+    models::PublicNameRecord,
+    state::{NAME_RECORDS, UNINDEXED_KEY},
+};
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
+
+/// Return x number of NameRecords pointing at the given contract
+pub fn query_names_by_contract(
+    ctx: ReadonlyContext,
+    msg: NamesByContractQueryMsg,
+) -> Result<NameRecordsQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let NamesByContractQueryMsg {
+        contract,
+        limit,
+        cursor,
+    } = msg;
+
+    if limit > MAX_REQUEST_LIMIT {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_REQUEST_LIMIT,
+        });
+    }
+
+    let min_bound = cursor.map(Bound::exclusive);
+
+    let mut name_records: Vec<PublicNameRecord> = Vec::with_capacity(limit as usize);
+    let mut next_cursor = None;
+
+    // Names without a contract don't point at any contract
+    if contract == UNINDEXED_KEY {
+        return Ok(NameRecordsQueryResponse {
+            name_records,
+            next_cursor,
+        });
+    }
+
+    for record in NAME_RECORDS
+        .idx
+        .contract
+        .prefix(contract)
+        .range(deps.storage, min_bound, None, Order::Ascending)
+        .take(limit as usize)
+    {
+        let (name, name_record) = record?;
+        next_cursor = Some(name.clone());
+        name_records.push(name_record.build_public_name_record(&ctx, name)?);
+    }
+
+    Ok(NameRecordsQueryResponse {
+        name_records,
+        next_cursor,
+    })
+}
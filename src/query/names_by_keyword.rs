@@ -0,0 +1,48 @@
+use super::name_records::MAX_REQUEST_LIMIT;
+use super::ReadonlyContext;
+use crate::msg::{NameRecordsQueryResponse, NamesByKeywordQueryMsg};
+use crate::utils::normalize_keyword;
+use crate::{
+    error::ContractError,
+    models::PublicNameRecord,
+    state::{KEYWORD_NAMES, NAME_RECORDS},
+};
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
+
+/// Return x number of NameRecords tagged with the given metadata keyword
+pub fn query_names_by_keyword(
+    ctx: ReadonlyContext,
+    msg: NamesByKeywordQueryMsg,
+) -> Result<NameRecordsQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let NamesByKeywordQueryMsg { keyword, limit, cursor } = msg;
+
+    if limit > MAX_REQUEST_LIMIT {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_REQUEST_LIMIT,
+        });
+    }
+
+    let keyword = normalize_keyword(&keyword);
+    let min_bound = cursor.as_ref().map(Bound::exclusive);
+
+    let mut name_records: Vec<PublicNameRecord> = Vec::with_capacity(limit as usize);
+    let mut next_cursor = None;
+
+    for name in KEYWORD_NAMES
+        .prefix(&keyword)
+        .keys(deps.storage, min_bound, None, Order::Ascending)
+        .take(limit as usize)
+    {
+        let name = name?;
+        let name_record = NAME_RECORDS.load(deps.storage, &name)?;
+        next_cursor = Some(name.clone());
+        name_records.push(name_record.build_public_name_record(&ctx, name)?);
+    }
+
+    Ok(NameRecordsQueryResponse {
+        name_records,
+        next_cursor,
+    })
+}
@@ -0,0 +1,44 @@
+use super::name_records::MAX_REQUEST_LIMIT;
+use super::ReadonlyContext;
+use crate::msg::{NameRecordsQueryResponse, NamesByOwnerQueryMsg};
+use crate::{error::ContractError, models::PublicNameRecord, state::NAME_RECORDS};
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
+
+/// Return x number of NameRecords owned by the given address
+pub fn query_names_by_owner(
+    ctx: ReadonlyContext,
+    msg: NamesByOwnerQueryMsg,
+) -> Result<NameRecordsQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let NamesByOwnerQueryMsg { owner, limit, cursor } = msg;
+
+    if limit > MAX_REQUEST_LIMIT {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_REQUEST_LIMIT,
+        });
+    }
+
+    let owner = deps.api.addr_validate(owner.as_str())?;
+    let min_bound = cursor.map(Bound::exclusive);
+
+    let mut name_records: Vec<PublicNameRecord> = Vec::with_capacity(limit as usize);
+    let mut next_cursor = None;
+
+    for record in NAME_RECORDS
+        .idx
+        .owner
+        .prefix(owner)
+        .range(deps.storage, min_bound, None, Order::Ascending)
+        .take(limit as usize)
+    {
+        let (name, name_record) = record?;
+        next_cursor = Some(name.clone());
+        name_records.push(name_record.build_public_name_record(&ctx, name)?);
+    }
+
+    Ok(NameRecordsQueryResponse {
+        name_records,
+        next_cursor,
+    })
+}
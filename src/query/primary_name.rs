@@ -0,0 +1,28 @@
+use cosmwasm_std::Addr;
+
+use crate::{
+    error::ContractError,
+    name::Name,
+    state::{is_owner_or_target, NAME_RECORDS, PRIMARY_NAMES},
+};
+
+use super::ReadonlyContext;
+
+/// Return the primary name of an address. The name is verified on every read
+/// so that names the address has since lost are not returned.
+pub fn query_primary_name(
+    ctx: ReadonlyContext,
+    address: Addr,
+) -> Result<Option<Name>, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    let cannonical_name = match PRIMARY_NAMES.may_load(deps.storage, &address)? {
+        Some(cannonical_name) => cannonical_name,
+        None => return Ok(None),
+    };
+
+    Ok(match NAME_RECORDS.may_load(deps.storage, &cannonical_name)? {
+        Some(record) if is_owner_or_target(deps.storage, &cannonical_name, &record, &address)? => Some(cannonical_name),
+        _ => None,
+    })
+}
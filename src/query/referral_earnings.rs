@@ -0,0 +1,30 @@
+use crate::{
+    error::ContractError,
+    msg::ReferralEarningsQueryResponse,
+    state::REFERRAL_EARNINGS,
+    token::{Token, TokenAmount},
+};
+use cosmwasm_std::{Addr, Order, StdResult};
+
+use super::ReadonlyContext;
+
+/// Return the registration fees paid out to a referrer so far
+pub fn query_referral_earnings(
+    ctx: ReadonlyContext,
+    referrer: Addr,
+) -> Result<ReferralEarningsQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    let earnings = REFERRAL_EARNINGS
+        .prefix(&referrer)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|entry| {
+            entry.map(|(token_key, amount)| TokenAmount {
+                token: Token::from_key(&token_key),
+                amount,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ReferralEarningsQueryResponse { referrer, earnings })
+}
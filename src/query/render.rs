@@ -1,7 +1,14 @@
 use cosmwasm_schema::cw_serde;
 use serde_json::Value;
 
-use crate::{error::ContractError, msg::RenderQueryMsg, state::resolve_contract_address};
+use crate::{
+    error::ContractError,
+    models::NameRecord,
+    msg::{RenderQueryMsg, RenderQueryResponse},
+    name::Name,
+    state::NAME_RECORDS,
+    utils::is_bech32_address,
+};
 
 use super::ReadonlyContext;
 
@@ -11,11 +18,12 @@ pub enum CwWebsiteQueryMsg {
 }
 
 /// Lookup a contract by name or address and proxy pass the template path and
-/// rendering context to it's own render smart query.
+/// rendering context to it's own render smart query. Names without a contract
+/// target return their content hash for the client to fetch instead.
 pub fn query_render(
     ctx: ReadonlyContext,
     msg: RenderQueryMsg,
-) -> Result<String, ContractError> {
+) -> Result<RenderQueryResponse, ContractError> {
     let ReadonlyContext { deps, .. } = ctx;
     let RenderQueryMsg {
         contract,
@@ -24,12 +32,31 @@ pub fn query_render(
     } = msg;
 
     // Get downstream contract address from given name or address
-    let contract_addr = resolve_contract_address(&deps, &contract)?;
+    let contract_addr = if is_bech32_address(&contract) {
+        contract
+    } else {
+        let cannonical_name = Name::parse_lenient(contract.clone());
+        match NAME_RECORDS.may_load(deps.storage, &cannonical_name)? {
+            Some(NameRecord {
+                contract: Some(contract_addr),
+                ..
+            }) => contract_addr,
+            Some(NameRecord {
+                content_hash: Some(content_hash),
+                ..
+            }) => return Ok(RenderQueryResponse::ContentHash { content_hash }),
+            _ => {
+                return Err(ContractError::NotFound {
+                    reason: format!("could not resolve contract address from {}", contract),
+                })
+            },
+        }
+    };
 
     // Render and return HTML
     let html: String = deps
         .querier
         .query_wasm_smart(contract_addr, &CwWebsiteQueryMsg::Render { path, context })?;
 
-    Ok(html)
+    Ok(RenderQueryResponse::Html(html))
 }
@@ -0,0 +1,38 @@
+use crate::{
+    error::ContractError,
+    models::{Chain, NameRecord},
+    name::Name,
+    state::{ADDRESS_RECORDS, NAME_RECORDS},
+    utils::bech32_prefix,
+};
+
+use super::ReadonlyContext;
+
+/// Resolve a name to its address on the given chain. Names without an explicit
+/// record for a bech32 chain fall back to their target contract when the
+/// contract lives on that chain.
+pub fn query_resolve_address(
+    ctx: ReadonlyContext,
+    name: Name,
+    chain: Chain,
+) -> Result<Option<String>, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    if let Some(address) = ADDRESS_RECORDS.may_load(deps.storage, (&name, &chain.to_key()))? {
+        return Ok(Some(address));
+    }
+
+    if let Chain::Bech32(hrp) = &chain {
+        if let Some(NameRecord {
+            contract: Some(contract),
+            ..
+        }) = NAME_RECORDS.may_load(deps.storage, &name)?
+        {
+            if bech32_prefix(&contract) == Some(hrp.to_lowercase()) {
+                return Ok(Some(contract));
+            }
+        }
+    }
+
+    Ok(None)
+}
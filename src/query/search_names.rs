@@ -0,0 +1,74 @@
+use super::name_records::MAX_REQUEST_LIMIT;
+use super::ReadonlyContext;
+use crate::msg::{SearchNamesQueryMsg, SearchNamesQueryResponse};
+use crate::{
+    error::ContractError,
+    models::PublicNameRecord,
+    name::Name,
+    state::{ensure_name_available, NAME_RECORDS},
+};
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
+
+/// Return x number of NameRecords whose name starts with the query, along
+/// with whether the query itself is still available for registration. A
+/// query that is a valid name is normalized first, so "Café" finds
+/// "xn--caf-dma". Unicode names are stored punycode-encoded though, so only
+/// their full name finds them: neither "caf" nor "café" finds "cafés".
+pub fn query_search_names(
+    ctx: ReadonlyContext,
+    msg: SearchNamesQueryMsg,
+) -> Result<SearchNamesQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+    let SearchNamesQueryMsg { query, limit, cursor } = msg;
+
+    if limit > MAX_REQUEST_LIMIT {
+        return Err(ContractError::TooManyRecords {
+            limit: MAX_REQUEST_LIMIT,
+        });
+    }
+
+    let prefix = match Name::try_from(query.as_str()) {
+        Ok(name) => name.into_string(),
+        Err(_) => query.to_ascii_lowercase(),
+    };
+    if prefix.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: "Search query must not be empty".to_string(),
+        });
+    }
+
+    // Start at the prefix itself or right after the cursor, whichever is later
+    let prefix_key = Name::from_canonical(prefix.clone());
+    let cursor = cursor.filter(|name| *name >= prefix_key);
+    let min_bound = match &cursor {
+        Some(name) => Bound::exclusive(name),
+        None => Bound::inclusive(&prefix_key),
+    };
+
+    let mut name_records: Vec<PublicNameRecord> = Vec::with_capacity(limit as usize);
+    let mut next_cursor = None;
+
+    // Names sharing the prefix are contiguous, so stop at the first one that doesn't
+    for record in NAME_RECORDS
+        .range(deps.storage, Some(min_bound), None, Order::Ascending)
+        .take_while(|item| {
+            item.as_ref()
+                .map_or(true, |(name, _)| name.as_str().starts_with(&prefix))
+        })
+        .take(limit as usize)
+    {
+        let (name, name_record) = record?;
+        next_cursor = Some(name.clone());
+        name_records.push(name_record.build_public_name_record(&ctx, name)?);
+    }
+
+    let is_available =
+        Name::try_from(prefix).is_ok_and(|name| ensure_name_available(deps.storage, ctx.env.block.time, &name).is_ok());
+
+    Ok(SearchNamesQueryResponse {
+        name_records,
+        next_cursor,
+        is_available,
+    })
+}
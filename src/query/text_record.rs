@@ -0,0 +1,14 @@
+use crate::{error::ContractError, name::Name, state::TEXT_RECORDS};
+
+use super::ReadonlyContext;
+
+/// Return the value of a name's text record, if set
+pub fn query_text_record(
+    ctx: ReadonlyContext,
+    name: Name,
+    key: String,
+) -> Result<Option<String>, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    Ok(TEXT_RECORDS.may_load(deps.storage, (&name, &key))?)
+}
@@ -0,0 +1,21 @@
+use crate::{error::ContractError, models::TextRecord, msg::TextRecordsQueryResponse, name::Name, state::TEXT_RECORDS};
+use cosmwasm_std::{Order, StdResult};
+
+use super::ReadonlyContext;
+
+/// Return all text records of a name. The number of records per name is
+/// capped, so no pagination is needed.
+pub fn query_text_records(
+    ctx: ReadonlyContext,
+    name: Name,
+) -> Result<TextRecordsQueryResponse, ContractError> {
+    let ReadonlyContext { deps, .. } = ctx;
+
+    let text_records = TEXT_RECORDS
+        .prefix(&name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(key, value)| TextRecord { key, value }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TextRecordsQueryResponse { text_records })
+}
@@ -0,0 +1,26 @@
+use crate::{error::ContractError, msg::VoucherQueryResponse, state::VOUCHERS};
+
+use super::ReadonlyContext;
+
+/// Return a voucher with how many uses it has left and whether it expired
+pub fn query_voucher(
+    ctx: ReadonlyContext,
+    code: String,
+) -> Result<VoucherQueryResponse, ContractError> {
+    let ReadonlyContext { deps, env } = ctx;
+
+    let voucher = VOUCHERS
+        .may_load(deps.storage, &code)?
+        .ok_or_else(|| ContractError::NotFound {
+            reason: format!("Voucher {} does not exist", code),
+        })?;
+
+    Ok(VoucherQueryResponse {
+        uses_left: voucher.max_uses.saturating_sub(voucher.uses),
+        is_expired: voucher
+            .expires_at
+            .is_some_and(|expires_at| env.block.time >= expires_at),
+        code,
+        voucher,
+    })
+}
@@ -1,41 +1,515 @@
-use cosmwasm_std::{Addr, Deps, Response};
-use cw_storage_plus::{Item, Map};
+use std::collections::BTreeSet;
+
+use cosmwasm_std::{attr, Addr, Deps, DepsMut, Env, Order, Response, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 use crate::{
     error::ContractError,
     execute::Context,
-    models::{NameMetadata, NameRecord},
-    msg::InstantiateMsg,
+    models::{Chain, GiftingPolicy, MigrationProgress, NameMetadata, NameRecord, Voucher},
+    msg::{InstantiateMsg, MigrateMsg, DEFAULT_MIGRATE_LIMIT, DEFAULT_RELEASE_COOLDOWN},
+    name::Name,
     token::TokenAmount,
-    utils::is_bech32_address,
+    utils::{
+        bech32_data_len, bech32_prefix, is_bech32_address, normalize_hrps, normalize_keyword, validate_name,
+        validate_referral_share, CONTRACT_ADDR_LEN,
+    },
 };
 
 pub const PRICE: Item<TokenAmount> = Item::new("unit_price");
 pub const FEE_RECIPIENT: Item<Addr> = Item::new("fee_recipient");
 pub const MAX_NAME_LEN: Item<u8> = Item::new("max_name_len");
-pub const NAME_RECORDS: Map<&String, NameRecord> = Map::new("name_records");
-pub const CONTRACT_ADDR_2_NAME: Map<&String, String> = Map::new("contract_addr_2_name");
-pub const NAME_METADATA: Map<&String, NameMetadata> = Map::new("name_metadata");
+pub const ADMIN: Item<Addr> = Item::new("admin");
+pub const ALLOWED_HRPS: Item<Vec<String>> = Item::new("allowed_hrps");
+pub const VERIFY_CONTRACT_TARGETS: Item<bool> = Item::new("verify_contract_targets");
+pub const PROBE_RENDER_TARGETS: Item<bool> = Item::new("probe_render_targets");
+pub const REQUIRE_TARGET_ADMIN: Item<bool> = Item::new("require_target_admin");
+pub const GIFTING_POLICY: Item<GiftingPolicy> = Item::new("gifting_policy");
+pub const RELEASE_COOLDOWN: Item<u64> = Item::new("release_cooldown");
+pub const REFERRAL_SHARE: Item<u8> = Item::new("referral_share");
+pub const NAME_RECORDS: IndexedMap<&Name, NameRecord, NameRecordIndexes> = IndexedMap::new(
+    "name_records",
+    NameRecordIndexes {
+        owner: MultiIndex::new(|_pk, r| r.owner.clone(), "name_records", "name_records__owner"),
+        contract: MultiIndex::new(
+            |_pk, r| r.contract.clone().unwrap_or_else(|| UNINDEXED_KEY.to_string()),
+            "name_records",
+            "name_records__contract",
+        ),
+        network: MultiIndex::new(|_pk, r| network_key(r), "name_records", "name_records__network"),
+        created: MultiIndex::new(|_pk, r| r.created_at.nanos(), "name_records", "name_records__created"),
+        network_created: MultiIndex::new(
+            |_pk, r| (network_key(r), r.created_at.nanos()),
+            "name_records",
+            "name_records__network_created",
+        ),
+    },
+);
+pub const CONTRACT_ADDR_2_NAME: Map<&String, Name> = Map::new("contract_addr_2_name");
+pub const NAME_METADATA: Map<&Name, NameMetadata> = Map::new("name_metadata");
+pub const KEYWORD_NAMES: Map<(&str, &Name), ()> = Map::new("keyword_names");
+pub const TEXT_RECORDS: Map<(&Name, &str), String> = Map::new("text_records");
+pub const ADDRESS_RECORDS: Map<(&Name, &str), String> = Map::new("address_records");
+pub const PRIMARY_NAMES: Map<&Addr, Name> = Map::new("primary_names");
+pub const RELEASED_NAMES: Map<&Name, Timestamp> = Map::new("released_names");
+pub const VOUCHERS: Map<&str, Voucher> = Map::new("vouchers");
+pub const REFERRAL_EARNINGS: Map<(&Addr, &str), Uint128> = Map::new("referral_earnings");
+pub const MIGRATION_PROGRESS: Item<MigrationProgress> = Item::new("migration_progress");
+
+/// Contract and network index key of names without a contract (or on no
+/// bech32 network), which no address or network prefix can collide with
+pub const UNINDEXED_KEY: &str = "\0";
+
+fn network_key(record: &NameRecord) -> String {
+    record
+        .contract
+        .as_deref()
+        .and_then(bech32_prefix)
+        .unwrap_or_else(|| UNINDEXED_KEY.to_string())
+}
+
+pub struct NameRecordIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, NameRecord, Name>,
+    pub contract: MultiIndex<'a, String, NameRecord, Name>,
+    pub network: MultiIndex<'a, String, NameRecord, Name>,
+    pub created: MultiIndex<'a, u64, NameRecord, Name>,
+    pub network_created: MultiIndex<'a, (String, u64), NameRecord, Name>,
+}
+
+impl<'a> IndexList<NameRecord> for NameRecordIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<NameRecord>> + '_> {
+        let v: Vec<&dyn Index<NameRecord>> = vec![
+            &self.owner,
+            &self.contract,
+            &self.network,
+            &self.created,
+            &self.network_created,
+        ];
+        Box::new(v.into_iter())
+    }
+}
 
 /// Top-level initialization of contract state
 pub fn init(
     ctx: Context,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
-    let Context { deps, .. } = ctx;
+    let Context { deps, info, .. } = ctx;
     PRICE.save(deps.storage, &msg.price)?;
     FEE_RECIPIENT.save(deps.storage, &deps.api.addr_validate(&msg.fee_recipient.as_str())?)?;
     MAX_NAME_LEN.save(deps.storage, &msg.max_name_len.max(1))?;
+    ADMIN.save(deps.storage, &info.sender)?;
+    ALLOWED_HRPS.save(deps.storage, &normalize_hrps(msg.allowed_hrps)?)?;
+    VERIFY_CONTRACT_TARGETS.save(deps.storage, &msg.verify_contract_targets)?;
+    PROBE_RENDER_TARGETS.save(deps.storage, &msg.probe_render_targets)?;
+    REQUIRE_TARGET_ADMIN.save(deps.storage, &msg.require_target_admin)?;
+    GIFTING_POLICY.save(deps.storage, &msg.gifting.validate(deps.api)?)?;
+    RELEASE_COOLDOWN.save(deps.storage, &msg.release_cooldown)?;
+    REFERRAL_SHARE.save(deps.storage, &validate_referral_share(msg.referral_share)?)?;
     Ok(Response::new().add_attribute("action", "instantiate"))
 }
 
+/// Top-level migration of contract state
+pub fn migrate(
+    deps: DepsMut,
+    env: Env,
+    msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    // Contracts instantiated before the admin role existed are administered
+    // by the given admin, or else by their wasm admin or creator
+    if let Some(admin) = msg.admin {
+        ADMIN.save(deps.storage, &deps.api.addr_validate(admin.as_str())?)?;
+    } else if !ADMIN.exists(deps.storage) {
+        let info = deps.querier.query_wasm_contract_info(env.contract.address)?;
+        ADMIN.save(deps.storage, &Addr::unchecked(info.admin.unwrap_or(info.creator)))?;
+    }
+    if !VERIFY_CONTRACT_TARGETS.exists(deps.storage) {
+        VERIFY_CONTRACT_TARGETS.save(deps.storage, &false)?;
+    }
+    if !PROBE_RENDER_TARGETS.exists(deps.storage) {
+        PROBE_RENDER_TARGETS.save(deps.storage, &false)?;
+    }
+    if !REQUIRE_TARGET_ADMIN.exists(deps.storage) {
+        REQUIRE_TARGET_ADMIN.save(deps.storage, &false)?;
+    }
+    if !GIFTING_POLICY.exists(deps.storage) {
+        GIFTING_POLICY.save(deps.storage, &GiftingPolicy::default())?;
+    }
+    if !RELEASE_COOLDOWN.exists(deps.storage) {
+        RELEASE_COOLDOWN.save(deps.storage, &DEFAULT_RELEASE_COOLDOWN)?;
+    }
+    if !REFERRAL_SHARE.exists(deps.storage) {
+        REFERRAL_SHARE.save(deps.storage, &0)?;
+    }
+
+    // Re-save the NameRecords so that secondary indexes added since the
+    // records were written get populated. Registries can be too large to do
+    // this in one transaction, so each call migrates the next batch.
+    let progress = match MIGRATION_PROGRESS.may_load(deps.storage)? {
+        Some(progress) => progress,
+        None => {
+            let collect_hrps = !ALLOWED_HRPS.exists(deps.storage);
+            if collect_hrps {
+                ALLOWED_HRPS.save(deps.storage, &vec![])?;
+            }
+            MigrationProgress {
+                cursor: None,
+                collect_hrps,
+            }
+        },
+    };
+    let limit = msg.limit.unwrap_or(DEFAULT_MIGRATE_LIMIT).max(1) as usize;
+    let records = NAME_RECORDS
+        .range(
+            deps.storage,
+            progress.cursor.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let done = records.len() < limit;
+    let cursor = records.last().map(|(name, _)| name.clone());
+    let migrated = records.len();
+    let records = rekey_legacy_names(deps.storage, records)?;
+
+    // Keep allowing the networks existing names already point at
+    if progress.collect_hrps {
+        let mut hrps: BTreeSet<String> = ALLOWED_HRPS.load(deps.storage)?.into_iter().collect();
+        hrps.extend(
+            records
+                .iter()
+                .filter_map(|(_, record)| record.contract.as_deref().and_then(bech32_prefix)),
+        );
+        ALLOWED_HRPS.save(deps.storage, &hrps.into_iter().collect())?;
+    }
+
+    for (name, record) in records.iter() {
+        NAME_RECORDS.save(deps.storage, name, record)?;
+        if let Some(contract_addr) = &record.contract {
+            init_primary_contract_name(deps.storage, contract_addr, name)?;
+        }
+        if let Some(meta) = NAME_METADATA.may_load(deps.storage, name)? {
+            update_keyword_index(deps.storage, name, None, meta.keywords.as_ref())?;
+        }
+    }
+
+    if done {
+        MIGRATION_PROGRESS.remove(deps.storage);
+    } else {
+        MIGRATION_PROGRESS.save(deps.storage, &MigrationProgress { cursor, ..progress })?;
+    }
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "migrate"),
+        attr("migrated", migrated.to_string()),
+        attr("done", done.to_string()),
+    ]))
+}
+
+/// Reverse lookup names as stored, without the normalization deserializing a
+/// Name applies
+const RAW_CONTRACT_ADDR_2_NAME: Map<&String, String> = Map::new("contract_addr_2_name");
+
+/// Move names registered before names were normalized, e.g. unicode names
+/// stored as is, to the canonical key lookups now map them to, and point the
+/// reverse lookup names storing them at the new key. Only data kept per name
+/// is moved, so the work stays bounded by the batch; the released contract
+/// had no primary names that could still refer to a legacy key. Names that
+/// aren't valid anymore keep their key, which lenient parsing maps them back
+/// to. The migration fails, listing the names, if a name's canonical key is
+/// already taken by another name, since neither could be told apart anymore.
+fn rekey_legacy_names(
+    storage: &mut dyn Storage,
+    records: Vec<(Name, NameRecord)>,
+) -> Result<Vec<(Name, NameRecord)>, ContractError> {
+    let mut collisions: Vec<String> = vec![];
+    let mut rekeyed = Vec::with_capacity(records.len());
+    for (legacy_name, record) in records {
+        let name = match Name::try_from(legacy_name.as_str()) {
+            Ok(name) if name != legacy_name => name,
+            _ => {
+                rekeyed.push((legacy_name, record));
+                continue;
+            },
+        };
+        if NAME_RECORDS.has(storage, &name) {
+            collisions.push(format!("{} ({})", legacy_name, name));
+            continue;
+        }
+        NAME_RECORDS.remove(storage, &legacy_name)?;
+        NAME_RECORDS.save(storage, &name, &record)?;
+        if let Some(meta) = NAME_METADATA.may_load(storage, &legacy_name)? {
+            update_keyword_index(storage, &legacy_name, meta.keywords.as_ref(), None)?;
+            NAME_METADATA.remove(storage, &legacy_name);
+            NAME_METADATA.save(storage, &name, &meta)?;
+        }
+        for records_map in [TEXT_RECORDS, ADDRESS_RECORDS] {
+            let entries = records_map
+                .prefix(&legacy_name)
+                .range(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for (key, value) in entries {
+                records_map.remove(storage, (&legacy_name, key.as_str()));
+                records_map.save(storage, (&name, key.as_str()), &value)?;
+            }
+        }
+        if let Some(contract_addr) = &record.contract {
+            if RAW_CONTRACT_ADDR_2_NAME.may_load(storage, contract_addr)?.as_deref() == Some(legacy_name.as_str()) {
+                CONTRACT_ADDR_2_NAME.save(storage, contract_addr, &name)?;
+            }
+        }
+        rekeyed.push((name, record));
+    }
+
+    if !collisions.is_empty() {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "Names collide with existing names once normalized: {}",
+                collisions.join(", ")
+            ),
+        });
+    }
+    Ok(rekeyed)
+}
+
+/// Ensure a canonical name can be registered: its syntax is valid, it isn't
+/// taken yet and, if it was released, its cooldown has passed
+pub fn ensure_name_available(
+    storage: &dyn Storage,
+    now: Timestamp,
+    cannonical_name: &Name,
+) -> Result<(), ContractError> {
+    cannonical_name.validate()?;
+    validate_name(cannonical_name.as_str(), MAX_NAME_LEN.load(storage)?)?;
+    if NAME_RECORDS.has(storage, cannonical_name) {
+        return Err(ContractError::NameExists {
+            name: cannonical_name.to_string(),
+        });
+    }
+    if let Some(available_at) = RELEASED_NAMES.may_load(storage, cannonical_name)? {
+        if now < available_at {
+            return Err(ContractError::ValidationError {
+                reason: format!(
+                    "Name {} was released and can be registered again at {}",
+                    cannonical_name,
+                    available_at.seconds()
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Ensure the sender is the contract admin
+pub fn ensure_admin(
+    storage: &dyn Storage,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if ADMIN.may_load(storage)?.as_ref() != Some(sender) {
+        return Err(ContractError::NotAuthorized {
+            reason: "Sender is not the admin".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensure an address can be a name's target contract. Addresses on this
+/// chain are validated through the API and, if configured, must belong to an
+/// existing contract. Addresses on other chains must use an allowed network
+/// prefix. Either way the address must have the length of a contract address.
+pub fn validate_contract_target(
+    deps: Deps,
+    env: &Env,
+    address: &str,
+) -> Result<(), ContractError> {
+    let hrp = bech32_prefix(address).ok_or_else(|| ContractError::ValidationError {
+        reason: format!("{} is not a valid bech32 address", address),
+    })?;
+    if bech32_data_len(address) != Some(CONTRACT_ADDR_LEN) {
+        return Err(ContractError::ValidationError {
+            reason: format!("{} is not a contract address", address),
+        });
+    }
+    if is_local_address(env, address) {
+        let address = deps.api.addr_validate(address)?;
+        if VERIFY_CONTRACT_TARGETS.load(deps.storage)? && deps.querier.query_wasm_contract_info(&address).is_err() {
+            return Err(ContractError::ValidationError {
+                reason: format!("{} is not a contract", address),
+            });
+        }
+    } else if !ALLOWED_HRPS.load(deps.storage)?.contains(&hrp) {
+        return Err(ContractError::ValidationError {
+            reason: format!("Network prefix {} is not allowed", hrp),
+        });
+    }
+    Ok(())
+}
+
+/// Whether the address is on the same chain as this contract
+pub fn is_local_address(
+    env: &Env,
+    address: &str,
+) -> bool {
+    bech32_prefix(address).is_some() && bech32_prefix(address) == bech32_prefix(env.contract.address.as_str())
+}
+
+/// Whether the sender is the admin or creator of the contract at the address
+pub fn is_target_admin(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    address: &str,
+) -> bool {
+    if !is_local_address(env, address) {
+        return false;
+    }
+    match deps.querier.query_wasm_contract_info(address) {
+        Ok(info) => info.creator == sender.as_str() || info.admin.as_deref() == Some(sender.as_str()),
+        Err(_) => false,
+    }
+}
+
+/// Whether the sender is the wasm admin of the contract at the address
+pub fn is_contract_admin(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    address: &str,
+) -> bool {
+    if !is_local_address(env, address) {
+        return false;
+    }
+    match deps.querier.query_wasm_contract_info(address) {
+        Ok(info) => info.admin.as_deref() == Some(sender.as_str()),
+        Err(_) => false,
+    }
+}
+
+/// If registration is restricted to target admins, ensure the sender is the
+/// admin or creator of the target contract. Contracts on other chains can't
+/// be looked up and are exempt.
+pub fn ensure_target_admin(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    address: &str,
+) -> Result<(), ContractError> {
+    if REQUIRE_TARGET_ADMIN.load(deps.storage)?
+        && is_local_address(env, address)
+        && !is_target_admin(deps, env, sender, address)
+    {
+        return Err(ContractError::NotAuthorized {
+            reason: "Only the admin or creator of the target contract can point a name at it".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Make the name the contract's primary (reverse lookup) name unless the
+/// contract already has one
+pub fn init_primary_contract_name(
+    storage: &mut dyn Storage,
+    contract_addr: &String,
+    name: &Name,
+) -> Result<(), ContractError> {
+    if !CONTRACT_ADDR_2_NAME.has(storage, contract_addr) {
+        CONTRACT_ADDR_2_NAME.save(storage, contract_addr, name)?;
+    }
+    Ok(())
+}
+
+/// Called after a name stops pointing at a contract. If it was the contract's
+/// primary name, fall back to another name still pointing at the contract.
+pub fn release_primary_contract_name(
+    storage: &mut dyn Storage,
+    contract_addr: &String,
+    name: &Name,
+) -> Result<(), ContractError> {
+    if CONTRACT_ADDR_2_NAME.may_load(storage, contract_addr)?.as_ref() != Some(name) {
+        return Ok(());
+    }
+    let next_name = NAME_RECORDS
+        .idx
+        .contract
+        .prefix(contract_addr.to_owned())
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?;
+    match next_name {
+        Some(next_name) => CONTRACT_ADDR_2_NAME.save(storage, contract_addr, &next_name)?,
+        None => CONTRACT_ADDR_2_NAME.remove(storage, contract_addr),
+    }
+    Ok(())
+}
+
+/// Whether the address owns the name or is what the name points at, either
+/// as its target contract or as its address record on the address's chain
+pub fn is_owner_or_target(
+    storage: &dyn Storage,
+    name: &Name,
+    record: &NameRecord,
+    address: &Addr,
+) -> Result<bool, ContractError> {
+    if record.owner == *address || record.contract.as_deref() == Some(address.as_str()) {
+        return Ok(true);
+    }
+    if let Some(hrp) = bech32_prefix(address.as_str()) {
+        let chain_key = Chain::Bech32(hrp).to_key();
+        if let Some(chain_address) = ADDRESS_RECORDS.may_load(storage, (name, &chain_key))? {
+            return Ok(chain_address == address.as_str());
+        }
+    }
+    Ok(false)
+}
+
+/// Delete all text and address records of a name
+pub fn clear_name_records(
+    storage: &mut dyn Storage,
+    name: &Name,
+) -> Result<(), ContractError> {
+    for records in [TEXT_RECORDS, ADDRESS_RECORDS] {
+        let keys = records
+            .prefix(name)
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for key in keys {
+            records.remove(storage, (name, &key));
+        }
+    }
+    Ok(())
+}
+
+/// Replace a name's entries in the keyword index, dropping those of keywords
+/// the name no longer has
+pub fn update_keyword_index(
+    storage: &mut dyn Storage,
+    name: &Name,
+    prev_keywords: Option<&Vec<String>>,
+    keywords: Option<&Vec<String>>,
+) -> Result<(), ContractError> {
+    for keyword in prev_keywords.into_iter().flatten() {
+        KEYWORD_NAMES.remove(storage, (&normalize_keyword(keyword), name));
+    }
+    for keyword in keywords.into_iter().flatten() {
+        let keyword = normalize_keyword(keyword);
+        if !keyword.is_empty() {
+            KEYWORD_NAMES.save(storage, (&keyword, name), &())?;
+        }
+    }
+    Ok(())
+}
+
 pub fn resolve_contract_address(
     deps: &Deps,
     addr_or_name: &String,
 ) -> Result<String, ContractError> {
     if is_bech32_address(addr_or_name) {
         Ok(addr_or_name.to_owned())
-    } else if let Some(NameRecord { contract, .. }) = NAME_RECORDS.may_load(deps.storage, addr_or_name)? {
+    } else if let Some(NameRecord {
+        contract: Some(contract),
+        ..
+    }) = NAME_RECORDS.may_load(deps.storage, &Name::parse_lenient(addr_or_name.to_owned()))?
+    {
         Ok(contract)
     } else {
         Err(ContractError::NotFound {
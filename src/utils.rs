@@ -1,5 +1,431 @@
-use bech32::decode;
+use bech32::{decode, Hrp};
+
+use crate::error::ContractError;
+
+/// Length of the data part of account (public key hash) addresses
+pub const ACCOUNT_ADDR_LEN: usize = 20;
+/// Length of the data part of contract addresses
+pub const CONTRACT_ADDR_LEN: usize = 32;
 
 pub fn is_bech32_address(address: &String) -> bool {
     decode(address).is_ok()
 }
+
+/// Return the human-readable part (network prefix) of a bech32 address
+pub fn bech32_prefix(address: &str) -> Option<String> {
+    decode(address).ok().map(|(hrp, _)| hrp.to_lowercase())
+}
+
+/// Return the length of the data part of a bech32 address
+pub fn bech32_data_len(address: &str) -> Option<usize> {
+    decode(address).ok().map(|(_, data)| data.len())
+}
+
+/// Lowercase, validate and dedupe a list of bech32 network prefixes
+pub fn normalize_hrps(hrps: Vec<String>) -> Result<Vec<String>, ContractError> {
+    let mut hrps = hrps
+        .into_iter()
+        .map(|hrp| match Hrp::parse(&hrp.to_lowercase()) {
+            Ok(parsed) => Ok(parsed.to_lowercase()),
+            Err(_) => Err(ContractError::ValidationError {
+                reason: format!("{} is not a valid bech32 prefix", hrp),
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    hrps.sort_unstable();
+    hrps.dedup();
+    Ok(hrps)
+}
+
+/// Normalize a metadata keyword for use as an index key
+pub fn normalize_keyword(keyword: &str) -> String {
+    keyword.trim().to_lowercase()
+}
+
+/// Canonicalize a possibly unicode name into the key it is stored under: the
+/// UTS-46 mapped (lowercased, NFC) label, punycode-encoded if not ASCII. Names
+/// mixing scripts are rejected to prevent homograph attacks.
+pub fn normalize_name(name: &str) -> Result<String, ContractError> {
+    let invalid = |reason: &str| ContractError::ValidationError {
+        reason: reason.to_string(),
+    };
+    let cannonical_name =
+        idna::domain_to_ascii(name).map_err(|_| invalid("Name is not a valid internationalized domain label"))?;
+    if cannonical_name.contains('.') {
+        return Err(invalid("Name must be a single label without dots"));
+    }
+    let (unicode_name, result) = idna::domain_to_unicode(&cannonical_name);
+    result.map_err(|_| invalid("Name is not a valid internationalized domain label"))?;
+    ensure_single_script(&unicode_name)?;
+    Ok(cannonical_name)
+}
+
+/// Rough Unicode script of a character. Digits, hyphens and combining marks
+/// are shared by all scripts.
+fn char_script(c: char) -> Option<&'static str> {
+    Some(match c as u32 {
+        0x30..=0x39 | 0x2d | 0x300..=0x36f => return None,
+        0x61..=0x7a | 0xc0..=0x24f | 0x1e00..=0x1eff => "Latin",
+        0x370..=0x3ff | 0x1f00..=0x1fff => "Greek",
+        0x400..=0x52f => "Cyrillic",
+        0x530..=0x58f => "Armenian",
+        0x590..=0x5ff => "Hebrew",
+        0x600..=0x6ff | 0x750..=0x77f => "Arabic",
+        0x900..=0x97f => "Devanagari",
+        0xe00..=0xe7f => "Thai",
+        0x1100..=0x11ff | 0x3130..=0x318f | 0xac00..=0xd7af => "Hangul",
+        0x3040..=0x309f => "Hiragana",
+        0x30a0..=0x30ff => "Katakana",
+        0x3400..=0x4dbf | 0x4e00..=0x9fff => "Han",
+        _ => "Other",
+    })
+}
+
+/// Ensure a name's characters belong to a single script, allowing the
+/// combinations used to write Japanese and Korean
+fn ensure_single_script(name: &str) -> Result<(), ContractError> {
+    let mut scripts: Vec<&str> = name.chars().filter_map(char_script).collect();
+    scripts.sort_unstable();
+    scripts.dedup();
+    let is_allowed = scripts.len() <= 1
+        || scripts
+            .iter()
+            .all(|script| ["Han", "Hiragana", "Katakana"].contains(script))
+        || scripts.iter().all(|script| ["Han", "Hangul"].contains(script));
+    if !is_allowed {
+        return Err(ContractError::ValidationError {
+            reason: format!("Name must not mix scripts ({})", scripts.join(", ")),
+        });
+    }
+    Ok(())
+}
+
+/// Ensure a canonical name is a valid subdomain label: lowercase letters,
+/// digits and inner hyphens, at most max_len characters
+pub fn validate_name(
+    name: &str,
+    max_len: u8,
+) -> Result<(), ContractError> {
+    if name.is_empty() || name.len() > max_len as usize {
+        return Err(ContractError::ValidationError {
+            reason: format!("Name must be between 1 and {} characters", max_len),
+        });
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(ContractError::ValidationError {
+            reason: "Name may only contain letters, digits and hyphens".to_string(),
+        });
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err(ContractError::ValidationError {
+            reason: "Name must not start or end with a hyphen".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensure a referral share is a percentage
+pub fn validate_referral_share(share: u8) -> Result<u8, ContractError> {
+    if share > 100 {
+        return Err(ContractError::ValidationError {
+            reason: "Referral share must be between 0 and 100 percent".to_string(),
+        });
+    }
+    Ok(share)
+}
+
+pub const MAX_VOUCHER_CODE_LEN: usize = 64;
+
+/// Ensure a voucher code is non-empty, short and easy to type
+pub fn validate_voucher_code(code: &str) -> Result<(), ContractError> {
+    if code.is_empty()
+        || code.len() > MAX_VOUCHER_CODE_LEN
+        || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "Voucher code must be 1 to {} letters, digits, hyphens or underscores",
+                MAX_VOUCHER_CODE_LEN
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub const MAX_SVG_LEN: usize = 16 * 1024;
+pub const MAX_ASSET_URL_LEN: usize = 512;
+pub const ASSET_URL_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// Elements an inline SVG may contain: shapes, text, paint servers and
+/// filters. Anything that can run code, load resources or rewrite attributes,
+/// e.g. <script>, <style>, <foreignObject>, <image>, <animate> and <set>, is
+/// left out.
+const SVG_ELEMENTS: [&str; 33] = [
+    "svg",
+    "g",
+    "defs",
+    "symbol",
+    "use",
+    "title",
+    "desc",
+    "path",
+    "rect",
+    "circle",
+    "ellipse",
+    "line",
+    "polyline",
+    "polygon",
+    "text",
+    "tspan",
+    "lineargradient",
+    "radialgradient",
+    "stop",
+    "pattern",
+    "clippath",
+    "mask",
+    "filter",
+    "feblend",
+    "fecolormatrix",
+    "fecomposite",
+    "feflood",
+    "fegaussianblur",
+    "femerge",
+    "femergenode",
+    "feoffset",
+    "fedropshadow",
+    "femorphology",
+];
+
+/// Presentation and geometry attributes inline SVGs may use
+const SVG_ATTRIBUTES: [&str; 74] = [
+    "xmlns",
+    "xmlns:xlink",
+    "xml:space",
+    "version",
+    "id",
+    "class",
+    "viewbox",
+    "preserveaspectratio",
+    "width",
+    "height",
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "cx",
+    "cy",
+    "r",
+    "rx",
+    "ry",
+    "fx",
+    "fy",
+    "dx",
+    "dy",
+    "d",
+    "points",
+    "transform",
+    "href",
+    "xlink:href",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-width",
+    "stroke-opacity",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-miterlimit",
+    "opacity",
+    "color",
+    "display",
+    "visibility",
+    "offset",
+    "stop-color",
+    "stop-opacity",
+    "gradientunits",
+    "gradienttransform",
+    "spreadmethod",
+    "patternunits",
+    "patterncontentunits",
+    "patterntransform",
+    "clip-path",
+    "clip-rule",
+    "clippathunits",
+    "mask",
+    "maskunits",
+    "maskcontentunits",
+    "filter",
+    "filterunits",
+    "primitiveunits",
+    "in",
+    "in2",
+    "result",
+    "mode",
+    "operator",
+    "values",
+    "type",
+    "stddeviation",
+    "flood-color",
+    "flood-opacity",
+    "radius",
+];
+
+/// Ensure an inline SVG is safe for the gateway to embed: bounded in size,
+/// made only of allowed elements and attributes, and only referencing
+/// fragments within itself
+pub fn validate_svg(svg: &str) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::ValidationError {
+        reason: format!("Invalid SVG: {}", reason),
+    };
+    if svg.len() > MAX_SVG_LEN {
+        return Err(invalid(&format!("must be at most {} bytes", MAX_SVG_LEN)));
+    }
+    let svg = svg.to_ascii_lowercase();
+    let mut rest = svg.trim_start();
+    if let Some(prolog) = rest.strip_prefix("<?xml") {
+        let end = prolog
+            .find("?>")
+            .ok_or_else(|| invalid("unterminated XML declaration"))?;
+        rest = prolog[end + 2..].trim_start();
+    }
+    if !rest.starts_with("<svg") {
+        return Err(invalid("must start with an <svg> element"));
+    }
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            let end = comment.find("-->").ok_or_else(|| invalid("unterminated comment"))?;
+            rest = &comment[end + 3..];
+        } else {
+            rest = validate_svg_tag(rest).map_err(|reason| invalid(&reason))?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether the byte can be part of an SVG element or attribute name
+fn is_svg_name_byte(b: u8) -> bool {
+    b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'-' | b':' | b'_' | b'.')
+}
+
+/// Validate the lowercased tag starting right after its '<', returning what
+/// follows it. Only XML whitespace may separate attributes, so characters
+/// HTML parsers also treat as whitespace, e.g. form feeds, can't smuggle
+/// attributes past this check.
+fn validate_svg_tag(tag: &str) -> Result<&str, String> {
+    let tag = tag.strip_prefix('/').unwrap_or(tag);
+    let name_len = tag.bytes().take_while(|b| is_svg_name_byte(*b)).count();
+    let name = &tag[..name_len];
+    if !SVG_ELEMENTS.contains(&name) {
+        return Err(format!("<{}> is not allowed", name));
+    }
+    let mut rest = &tag[name_len..];
+    loop {
+        let trimmed = rest.trim_start_matches([' ', '\t', '\r', '\n']);
+        let is_separated = trimmed.len() < rest.len();
+        rest = trimmed;
+        if let Some(rest) = rest.strip_prefix("/>").or_else(|| rest.strip_prefix('>')) {
+            return Ok(rest);
+        }
+        let attr_len = rest.bytes().take_while(|b| is_svg_name_byte(*b)).count();
+        if !is_separated || attr_len == 0 {
+            return Err(format!("malformed <{}> tag", name));
+        }
+        let attr = &rest[..attr_len];
+        let value = rest[attr_len..]
+            .trim_start_matches([' ', '\t', '\r', '\n'])
+            .strip_prefix('=')
+            .map(|value| value.trim_start_matches([' ', '\t', '\r', '\n']))
+            .ok_or_else(|| format!("{} attribute must have a value", attr))?;
+        let quote = value
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+            .ok_or_else(|| format!("{} attribute value must be quoted", attr))?;
+        let value_len = value[1..]
+            .find(quote)
+            .ok_or_else(|| format!("unterminated {} attribute value", attr))?;
+        validate_svg_attribute(attr, &value[1..1 + value_len])?;
+        rest = &value[value_len + 2..];
+    }
+}
+
+/// Ensure an attribute is allowed and, once character references are
+/// decoded, only references fragments within the SVG
+fn validate_svg_attribute(
+    attr: &str,
+    value: &str,
+) -> Result<(), String> {
+    if attr.starts_with("on") {
+        return Err("event handler attributes are not allowed".to_string());
+    }
+    if !SVG_ATTRIBUTES.contains(&attr) {
+        return Err(format!("{} attribute is not allowed", attr));
+    }
+    let value = decode_xml_references(value).ok_or_else(|| format!("invalid reference in {} attribute", attr))?;
+    let value: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_lowercase();
+    let is_external_href = matches!(attr, "href" | "xlink:href") && !value.starts_with('#');
+    let is_external_url = value
+        .match_indices("url(")
+        .any(|(i, _)| !value[i + 4..].trim_start_matches(['"', '\'']).starts_with('#'));
+    if is_external_href || is_external_url || value.contains("javascript:") {
+        return Err("external references are not allowed".to_string());
+    }
+    Ok(())
+}
+
+/// Decode the predefined XML entities and numeric character references in an
+/// attribute value, or None if it contains an unknown or malformed one
+fn decode_xml_references(value: &str) -> Option<String> {
+    let mut decoded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let end = start + rest[start..].find(';')?;
+        decoded.push(match &rest[start + 1..end] {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            reference => {
+                let code = match reference.strip_prefix("#x") {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => reference.strip_prefix('#')?.parse().ok()?,
+                };
+                char::from_u32(code)?
+            },
+        });
+        rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+    Some(decoded)
+}
+
+/// Ensure an asset URL uses an allowed scheme and is bounded in size
+pub fn validate_asset_url(url: &str) -> Result<(), ContractError> {
+    let has_scheme = ASSET_URL_SCHEMES
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && url.to_ascii_lowercase().starts_with(scheme));
+    if !has_scheme || url.len() > MAX_ASSET_URL_LEN || url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(ContractError::ValidationError {
+            reason: format!(
+                "Image URL must use one of {} and be at most {} characters",
+                ASSET_URL_SCHEMES.join(", "),
+                MAX_ASSET_URL_LEN
+            ),
+        });
+    }
+    Ok(())
+}
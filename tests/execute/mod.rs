@@ -1 +1,12 @@
+pub mod test_batch;
+pub mod test_claim_name;
+pub mod test_content_hash;
+pub mod test_controller;
+pub mod test_gifting;
+pub mod test_idn_names;
+pub mod test_local_targets;
+pub mod test_release_name;
+pub mod test_text_records;
+pub mod test_update_config;
 pub mod test_update_metadata;
+pub mod test_vouchers;
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod test_batch {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_batch_register_and_update_metadata() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = |name: &str| RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        };
+        let is_available = |app: &cw_multi_test::App, name: &str| {
+            let query_msg = msg::QueryMsg::IsAvailable { name: name.to_string() };
+            let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            resp.is_available
+        };
+
+        //the batch must be paid for in full
+        let batch_msg = ExecuteMsg::BatchRegister(BatchRegisterMsg {
+            names: vec![reg_msg("alpha"), reg_msg("bravo"), reg_msg("charlie")],
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &batch_msg, &coins(2, "juno"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientFunds { exp_amount: 3 },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &batch_msg, &coins(3, "juno"))
+            .unwrap();
+        assert!(!is_available(&app, "alpha"));
+        assert!(!is_available(&app, "charlie"));
+
+        //if any name fails, none are registered
+        let batch_msg = ExecuteMsg::BatchRegister(BatchRegisterMsg {
+            names: vec![reg_msg("delta"), reg_msg("alpha")],
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &batch_msg, &coins(2, "juno"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NameExists {
+                name: "alpha".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        assert!(is_available(&app, "delta"));
+
+        //batches are bounded
+        let names = (0..=execute::batch_register::MAX_BATCH_EXECUTE_SIZE)
+            .map(|i| reg_msg(&format!("name{}", i)))
+            .collect();
+        let batch_msg = ExecuteMsg::BatchRegister(BatchRegisterMsg { names });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &batch_msg, &coins(100, "juno"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::TooManyRecords {
+                limit: execute::batch_register::MAX_BATCH_EXECUTE_SIZE,
+            },
+            err.downcast().unwrap()
+        );
+
+        //update the metadata of several names at once
+        let update_msg = |name: &str, title: &str| UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        };
+        let batch_msg = ExecuteMsg::BatchUpdateMetadata(BatchUpdateMetadataMsg {
+            updates: vec![update_msg("alpha", "Alpha"), update_msg("bravo", "Bravo")],
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &batch_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "bravo".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.meta.title, Some("Bravo".to_string()));
+
+        //if any update fails, none are applied
+        let batch_msg = ExecuteMsg::BatchUpdateMetadata(BatchUpdateMetadataMsg {
+            updates: vec![update_msg("alpha", "New Alpha"), update_msg("delta", "Delta")],
+        });
+        let _err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &batch_msg, &[])
+            .unwrap_err();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "alpha".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.meta.title, Some("Alpha".to_string()));
+    }
+}
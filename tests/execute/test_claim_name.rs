@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod test_claim_name {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_claim_name() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let squatter = Addr::unchecked("squatter");
+
+        //a name is registered for a contract by someone other than its admin
+        let name = "example".to_string();
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: squatter.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //names can't be claimed unless registration is restricted to target admins
+        let claim_msg = ExecuteMsg::ClaimName(ClaimNameMsg {
+            name: name.parse().unwrap(),
+        });
+        let err = app
+            .execute_contract(owner_address.clone(), addr.clone(), &claim_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "Names can only be claimed while registration is restricted to target admins".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        let update_config_msg = ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            admin: None,
+            allowed_hrps: None,
+            verify_contract_targets: None,
+            probe_render_targets: None,
+            require_target_admin: Some(true),
+            gifting: None,
+            release_cooldown: None,
+            referral_share: None,
+        });
+        let _resp = app
+            .execute_contract(owner_address.clone(), addr.clone(), &update_config_msg, &[])
+            .unwrap();
+
+        //only the admin or creator of the target contract can claim the name,
+        //and contracts on other chains can't be looked up
+        let err = app
+            .execute_contract(owner_address.clone(), addr.clone(), &claim_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "Only the admin or creator of the target contract can claim this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //names pointing at content hashes can't be claimed
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: squatter.clone(),
+            name: "static".parse().unwrap(),
+            address: None,
+            content_hash: Some(ContentHash::Ipfs(
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            )),
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+        let claim_msg = ExecuteMsg::ClaimName(ClaimNameMsg {
+            name: "static".parse().unwrap(),
+        });
+        let err = app
+            .execute_contract(owner_address.clone(), addr.clone(), &claim_msg, &[])
+            .unwrap_err();
+        assert!(matches!(err.downcast().unwrap(), ContractError::NotAuthorized { .. }));
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod test_content_hash {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_content_hash() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let cid = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string();
+
+        //a name needs a contract or content hash to point at
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "example".parse().unwrap(),
+            address: None,
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Name must point at a contract or content hash".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //content hashes are validated
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "example".parse().unwrap(),
+            address: None,
+            content_hash: Some(ContentHash::Ipfs("not_a_cid".to_string())),
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Invalid content hash".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //register a name hosted on IPFS
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "example".parse().unwrap(),
+            address: None,
+            content_hash: Some(ContentHash::Ipfs(cid.clone())),
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "example".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.contract, None);
+        assert_eq!(resp.content_hash, Some(ContentHash::Ipfs(cid.clone())));
+
+        //rendering a name without a contract returns its content hash
+        let query_msg = msg::QueryMsg::Render(RenderQueryMsg {
+            contract: "example".to_string(),
+            path: "/".to_string(),
+            context: None,
+        });
+        let resp: RenderQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(
+            resp,
+            RenderQueryResponse::ContentHash {
+                content_hash: ContentHash::Ipfs(cid.clone()),
+            }
+        );
+
+        //the content hash can be changed but not cleared while it's the only target
+        let set_msg = ExecuteMsg::SetContentHash(SetContentHashMsg {
+            name: "example".parse().unwrap(),
+            content_hash: Some(ContentHash::Url("https://example.com/site".to_string())),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let set_msg = ExecuteMsg::SetContentHash(SetContentHashMsg {
+            name: "example".parse().unwrap(),
+            content_hash: None,
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Name must point at a contract or content hash".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //once the name points at a contract, the content hash can be cleared
+        let update_contract_msg = ExecuteMsg::UpdateContract(UpdateContractMsg {
+            name: "example".parse().unwrap(),
+            address: Some("example_site_cw_address".into_bech32().to_string()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_contract_msg, &[])
+            .unwrap();
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "example".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.contract, Some("example_site_cw_address".into_bech32().to_string()));
+        assert_eq!(resp.content_hash, None);
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod test_controller {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_controller_and_transfer() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let controller = Addr::unchecked("controller");
+        let new_owner = Addr::unchecked("new_owner");
+
+        //register a name
+        let name = "example".to_string();
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //the controller defaults to the owner
+        let query_msg = msg::QueryMsg::NameRecord { contract: name.clone() };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.controller, name_owner);
+
+        //only the owner can set a controller
+        let set_controller_msg = ExecuteMsg::SetController(SetControllerMsg {
+            name: name.parse().unwrap(),
+            controller: Some(controller.clone()),
+        });
+        let err = app
+            .execute_contract(controller.clone(), addr.clone(), &set_controller_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_controller_msg, &[])
+            .unwrap();
+
+        //the controller can update the target contract and metadata
+        let new_site_address = "new_example_site_cw_address".into_bech32().to_string();
+        let update_contract_msg = ExecuteMsg::UpdateContract(UpdateContractMsg {
+            name: name.parse().unwrap(),
+            address: Some(new_site_address.clone()),
+        });
+        let _resp = app
+            .execute_contract(controller.clone(), addr.clone(), &update_contract_msg, &[])
+            .unwrap();
+
+        let update_msg = ExecuteMsg::UpdateMetadata(UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                title: Some("example_title".to_string()),
+                ..Default::default()
+            },
+        });
+        let _resp = app
+            .execute_contract(controller.clone(), addr.clone(), &update_msg, &[])
+            .unwrap();
+
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.owner, name_owner);
+        assert_eq!(resp.controller, controller);
+        assert_eq!(resp.contract, Some(new_site_address));
+        assert_eq!(resp.meta.title, Some("example_title".to_string()));
+
+        //the owner no longer controls the name while a controller is set
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the controller of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //the controller cannot transfer the name
+        let transfer_msg = ExecuteMsg::Transfer(TransferMsg {
+            name: name.parse().unwrap(),
+            owner: new_owner.clone(),
+        });
+        let err = app
+            .execute_contract(controller.clone(), addr.clone(), &transfer_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //transferring the name resets the controller to the new owner
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &transfer_msg, &[])
+            .unwrap();
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.owner, new_owner);
+        assert_eq!(resp.controller, new_owner);
+
+        let err = app
+            .execute_contract(controller.clone(), addr.clone(), &update_contract_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the controller of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test_gifting {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_register_for_others() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let sponsor = Addr::unchecked(owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+
+        let register = |app: &mut cw_multi_test::App, sender: &Addr, name: &str| {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(site_address.clone()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            app.execute_contract(sender.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+        };
+        let set_gifting = |app: &mut cw_multi_test::App, gifting: GiftingPolicy| {
+            let update_config_msg = ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+                admin: None,
+                allowed_hrps: None,
+                verify_contract_targets: None,
+                probe_render_targets: None,
+                require_target_admin: None,
+                gifting: Some(gifting),
+                release_cooldown: None,
+                referral_share: None,
+            });
+            app.execute_contract(owner_address.clone(), addr.clone(), &update_config_msg, &[])
+                .unwrap();
+        };
+
+        //anyone can register a name for someone else, and the payer is recorded
+        let _resp = register(&mut app, &sponsor, "example").unwrap();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "example".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.owner, name_owner);
+        assert_eq!(resp.registrant, Some(sponsor.clone()));
+
+        //only sponsors can register names for others once restricted
+        set_gifting(&mut app, GiftingPolicy::Sponsors(vec![Addr::unchecked("onboarding")]));
+        let err = register(&mut app, &sponsor, "example2").unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You may not register names for others".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        set_gifting(&mut app, GiftingPolicy::Sponsors(vec![sponsor.clone()]));
+        let _resp = register(&mut app, &sponsor, "example2").unwrap();
+
+        //owners can always register names for themselves
+        set_gifting(&mut app, GiftingPolicy::Disabled);
+        let err = register(&mut app, &sponsor, "example3").unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You may not register names for others".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = register(&mut app, &name_owner, "example3").unwrap();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "example3".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.registrant, Some(name_owner.clone()));
+    }
+}
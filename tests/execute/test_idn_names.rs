@@ -0,0 +1,342 @@
+#[cfg(test)]
+mod test_idn_names {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cosmwasm_std::Event;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_storage_plus::Map;
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    /// Names and their metadata as stored before names were normalized
+    const LEGACY_NAME_RECORDS: Map<&str, NameRecord> = Map::new("name_records");
+    const LEGACY_NAME_METADATA: Map<&str, NameMetadata> = Map::new("name_metadata");
+
+    #[test]
+    fn test_exec_register_idn_names() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.max_name_len = 20;
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+
+        //unicode names are stored under their punycode form
+        let query_msg = msg::QueryMsg::IsAvailable {
+            name: "Café".to_string(),
+        };
+        let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "xn--caf-dma");
+        assert!(resp.is_available);
+
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "Café".parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //every spelling of the name resolves to the same record
+        for name in ["café", "CAFÉ", "cafe\u{301}", "xn--caf-dma"] {
+            let query_msg = msg::QueryMsg::NameRecord {
+                contract: name.to_string(),
+            };
+            let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            assert_eq!(resp.cannonical_name, "xn--caf-dma");
+        }
+
+        //and is found when searching for it
+        let query_msg = msg::QueryMsg::SearchNames(SearchNamesQueryMsg {
+            query: "Café".to_string(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: SearchNamesQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 1);
+        assert_eq!(resp.name_records[0].cannonical_name, "xn--caf-dma");
+        assert!(!resp.is_available);
+
+        //equivalent spellings can't be registered again
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "cafe\u{301}".parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NameExists {
+                name: "xn--caf-dma".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //names mixing scripts are rejected, e.g. a cyrillic "а" in "pаypal"
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Name must not mix scripts (Cyrillic, Latin)".to_string(),
+            },
+            "p\u{430}ypal".parse::<name::Name>().unwrap_err()
+        );
+
+        //invalid names in raw messages are rejected when the contract parses them
+        let reg_msg = serde_json::json!({
+            "register": {
+                "owner": name_owner,
+                "name": "p\u{430}ypal",
+                "address": site_address,
+            }
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Name must not mix scripts (Cyrillic, Latin)"));
+
+        //single-script unicode names are fine
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "пример".parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //names can't contain dots
+        let query_msg = msg::QueryMsg::IsAvailable {
+            name: "example.com".to_string(),
+        };
+        let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert!(!resp.is_available);
+    }
+
+    #[test]
+    fn test_legacy_names() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.max_name_len = 20;
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+
+        //names registered before names were validated, stored as they were
+        //back then: lowercased but otherwise as sent
+        let legacy_record = NameRecord {
+            owner: name_owner.clone(),
+            controller: None,
+            contract: Some(site_address.clone()),
+            content_hash: None,
+            created_at: app.block_info().time,
+            registrant: None,
+            render_capable: None,
+        };
+        {
+            let mut storage = app.contract_storage_mut(&addr);
+            for legacy_name in ["legacy_name", "café"] {
+                LEGACY_NAME_RECORDS
+                    .save(storage.as_mut(), legacy_name, &legacy_record)
+                    .unwrap();
+                LEGACY_NAME_METADATA
+                    .save(storage.as_mut(), legacy_name, &NameMetadata::default())
+                    .unwrap();
+            }
+        }
+        let code_id = app.wrap().query_wasm_contract_info(&addr).unwrap().code_id;
+        let _resp = app
+            .migrate_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &MigrateMsg {
+                    admin: None,
+                    limit: None,
+                },
+                code_id,
+            )
+            .unwrap();
+
+        //names that aren't valid anymore can still be looked up and managed
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "legacy_name".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "legacy_name");
+        let set_msg = serde_json::json!({
+            "set_text_record": {
+                "name": "legacy_name",
+                "key": "twitter",
+                "value": "@legacy",
+            }
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        //but can't be registered anew
+        let reg_msg = serde_json::json!({
+            "register": {
+                "owner": name_owner,
+                "name": "other_name",
+                "address": site_address,
+            }
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap_err();
+        assert!(err.root_cause().to_string().starts_with("ValidationError"));
+
+        //unicode names are moved to their punycode form by the migration
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "Café".to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "xn--caf-dma");
+        let storage = app.contract_storage(&addr);
+        assert!(!LEGACY_NAME_RECORDS.has(storage.as_ref(), "café"));
+    }
+
+    #[test]
+    fn test_migrate_in_batches() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), 1000);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        //records written before the indexes existed
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let legacy_record = NameRecord {
+            owner: name_owner.clone(),
+            controller: None,
+            contract: None,
+            content_hash: None,
+            created_at: app.block_info().time,
+            registrant: None,
+            render_capable: None,
+        };
+        {
+            let mut storage = app.contract_storage_mut(&addr);
+            for legacy_name in ["alpha", "beta", "gamma"] {
+                LEGACY_NAME_RECORDS
+                    .save(storage.as_mut(), legacy_name, &legacy_record)
+                    .unwrap();
+                LEGACY_NAME_METADATA
+                    .save(storage.as_mut(), legacy_name, &NameMetadata::default())
+                    .unwrap();
+            }
+        }
+        let code_id = app.wrap().query_wasm_contract_info(&addr).unwrap().code_id;
+        let query_msg = msg::QueryMsg::NamesByOwner(NamesByOwnerQueryMsg {
+            owner: name_owner.clone(),
+            limit: 10,
+            cursor: None,
+        });
+
+        //each migration indexes the next batch until all records are done
+        let migrate_msg = MigrateMsg {
+            admin: None,
+            limit: Some(2),
+        };
+        let resp = app
+            .migrate_contract(owner_address.clone(), addr.clone(), &migrate_msg, code_id)
+            .unwrap();
+        assert!(resp.has_event(&Event::new("wasm").add_attribute("done", "false")));
+        let names: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(names.name_records.len(), 2);
+
+        let resp = app
+            .migrate_contract(owner_address.clone(), addr.clone(), &migrate_msg, code_id)
+            .unwrap();
+        assert!(resp.has_event(&Event::new("wasm").add_attribute("done", "true")));
+        let names: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(names.name_records.len(), 3);
+    }
+
+    #[test]
+    fn test_colliding_legacy_names() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), 1000);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.max_name_len = 20;
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "café".parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //a legacy name normalizing to a registered name can't be migrated
+        let legacy_record = NameRecord {
+            owner: name_owner.clone(),
+            controller: None,
+            contract: Some(site_address.clone()),
+            content_hash: None,
+            created_at: app.block_info().time,
+            registrant: None,
+            render_capable: None,
+        };
+        LEGACY_NAME_RECORDS
+            .save(app.contract_storage_mut(&addr).as_mut(), "café", &legacy_record)
+            .unwrap();
+        let code_id = app.wrap().query_wasm_contract_info(&addr).unwrap().code_id;
+        let err = app
+            .migrate_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &MigrateMsg {
+                    admin: None,
+                    limit: None,
+                },
+                code_id,
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Names collide with existing names once normalized: café (xn--caf-dma)".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+    }
+}
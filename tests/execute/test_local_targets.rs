@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod test_local_targets {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::{Addr, Empty};
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_probe_render_targets() {
+        let owner_address = "owner".into_bech32();
+        let name_owner = "name_owner".into_bech32();
+        let mut app = def_bech32_app(&[&owner_address, &name_owner], 1000);
+        let website_code_id = app.store_code(website_contract());
+        let plain_code_id = app.store_code(plain_contract());
+
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.fee_recipient = "fee_recipient".into_bech32();
+            inst_msg.allowed_hrps = vec![];
+            inst_msg.verify_contract_targets = true;
+            inst_msg.probe_render_targets = true;
+        });
+        let website_addr = app
+            .instantiate_contract(website_code_id, name_owner.clone(), &Empty {}, &[], "website", None)
+            .unwrap();
+        let plain_addr = app
+            .instantiate_contract(plain_code_id, name_owner.clone(), &Empty {}, &[], "plain", None)
+            .unwrap();
+
+        let reg_msg = |name: &str, address: &Addr| {
+            ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(address.to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            })
+        };
+        let render_capable = |app: &Bech32App, name: &str| {
+            let query_msg = msg::QueryMsg::NameRecord {
+                contract: name.to_string(),
+            };
+            let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            resp.render_capable
+        };
+
+        //contracts that answer render queries are marked as render capable
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("site", &website_addr),
+                &coins(1, "juno"),
+            )
+            .unwrap();
+        assert_eq!(render_capable(&app, "site"), Some(true));
+        let query_msg = msg::QueryMsg::Render(RenderQueryMsg {
+            contract: "site".to_string(),
+            path: "/about".to_string(),
+            context: None,
+        });
+        let resp: String = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, "<html>/about</html>");
+
+        //contracts that don't are not
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("plain", &plain_addr),
+                &coins(1, "juno"),
+            )
+            .unwrap();
+        assert_eq!(render_capable(&app, "plain"), Some(false));
+
+        //the probe is repeated when the target changes
+        let update_msg = ExecuteMsg::UpdateContract(UpdateContractMsg {
+            name: "plain".parse().unwrap(),
+            address: Some(website_addr.to_string()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_msg, &[])
+            .unwrap();
+        assert_eq!(render_capable(&app, "plain"), Some(true));
+
+        //addresses on this chain must belong to a contract
+        let account_addr = "not_a_contract".into_bech32();
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("account", &account_addr),
+                &coins(1, "juno"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: format!("{} is not a contract", account_addr),
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_claim_clears_squatter_records() {
+        let owner_address = "owner".into_bech32();
+        let name_owner = "name_owner".into_bech32();
+        let squatter = "squatter".into_bech32();
+        let mut app = def_bech32_app(&[&owner_address, &name_owner, &squatter], 1000);
+        let website_code_id = app.store_code(website_contract());
+
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.fee_recipient = "fee_recipient".into_bech32();
+            inst_msg.allowed_hrps = vec![];
+            inst_msg.verify_contract_targets = true;
+        });
+        let website_addr = app
+            .instantiate_contract(website_code_id, name_owner.clone(), &Empty {}, &[], "website", None)
+            .unwrap();
+        let name: name::Name = "site".parse().unwrap();
+
+        //the squatter registers the name and sets it up while anyone may
+        let msgs = vec![
+            ExecuteMsg::Register(RegisterMsg {
+                owner: squatter.clone(),
+                name: name.clone(),
+                address: Some(website_addr.to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            }),
+            ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+                name: name.clone(),
+                key: "url".to_string(),
+                value: "https://squatter.example".to_string(),
+            }),
+            ExecuteMsg::SetPrimaryName(SetPrimaryNameMsg {
+                name: Some(name.clone()),
+            }),
+        ];
+        for msg in msgs {
+            let funds = match msg {
+                ExecuteMsg::Register(_) => coins(1, "juno"),
+                _ => vec![],
+            };
+            let _resp = app
+                .execute_contract(squatter.clone(), addr.clone(), &msg, &funds)
+                .unwrap();
+        }
+
+        let update_msg = ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            admin: None,
+            allowed_hrps: None,
+            verify_contract_targets: None,
+            probe_render_targets: None,
+            require_target_admin: Some(true),
+            gifting: None,
+            release_cooldown: None,
+            referral_share: None,
+        });
+        let _resp = app
+            .execute_contract(owner_address.clone(), addr.clone(), &update_msg, &[])
+            .unwrap();
+
+        //the creator of the target contract claims the name
+        let claim_msg = ExecuteMsg::ClaimName(ClaimNameMsg { name: name.clone() });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &claim_msg, &[])
+            .unwrap();
+
+        //nothing the squatter set up is handed over
+        let resp: TextRecordsQueryResponse = app
+            .wrap()
+            .query_wasm_smart(addr.clone(), &msg::QueryMsg::TextRecords { name: name.clone() })
+            .unwrap();
+        assert!(resp.text_records.is_empty());
+        let resp: Option<name::Name> = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &msg::QueryMsg::PrimaryName {
+                    address: squatter.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp, None);
+        let resp: PublicNameRecord = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &msg::QueryMsg::NameRecord {
+                    contract: name.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.owner, name_owner);
+        assert_eq!(resp.controller, name_owner);
+    }
+
+    #[test]
+    fn test_admin_sets_primary_contract_name() {
+        let owner_address = "owner".into_bech32();
+        let name_owner = "name_owner".into_bech32();
+        let site_admin = "site_admin".into_bech32();
+        let mut app = def_bech32_app(&[&owner_address, &name_owner], 1000);
+        let website_code_id = app.store_code(website_contract());
+
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.fee_recipient = "fee_recipient".into_bech32();
+        });
+        let website_addr = app
+            .instantiate_contract(
+                website_code_id,
+                name_owner.clone(),
+                &Empty {},
+                &[],
+                "website",
+                Some(site_admin.to_string()),
+            )
+            .unwrap();
+        for name in ["site", "flagship"] {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(website_addr.to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        //the wasm admin of the contract can change its primary name
+        let set_primary_msg = ExecuteMsg::SetPrimaryContractName(SetPrimaryContractNameMsg {
+            name: "flagship".parse().unwrap(),
+        });
+        let _resp = app
+            .execute_contract(site_admin.clone(), addr.clone(), &set_primary_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: website_addr.to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "flagship");
+    }
+}
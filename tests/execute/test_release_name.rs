@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod test_release_name {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_release_name() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let stranger = Addr::unchecked("stranger");
+
+        //register a name with a keyword and a text record
+        let name = "example".to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some("example_site_cw_address".into_bech32().to_string()),
+            content_hash: None,
+            meta: Some(NameMetadata {
+                keywords: Some(vec!["games".to_string()]),
+                ..Default::default()
+            }),
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+        let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+            value: "@example".to_string(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        //only the owner can release the name
+        let release_msg = ExecuteMsg::ReleaseName(ReleaseNameMsg {
+            name: name.parse().unwrap(),
+        });
+        let err = app
+            .execute_contract(stranger.clone(), addr.clone(), &release_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &release_msg, &[])
+            .unwrap();
+
+        //the record and everything attached to it is gone
+        let query_msg = msg::QueryMsg::NameRecord { contract: name.clone() };
+        let resp: Result<PublicNameRecord, _> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
+        assert!(resp.is_err());
+        let query_msg = msg::QueryMsg::TextRecord {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+        let query_msg = msg::QueryMsg::NamesByKeyword(NamesByKeywordQueryMsg {
+            keyword: "games".to_string(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert!(resp.name_records.is_empty());
+
+        //the name can't be registered again until the cooldown has passed
+        let query_msg = msg::QueryMsg::IsAvailable { name: name.clone() };
+        let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert!(!resp.is_available);
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap_err();
+        assert!(matches!(err.downcast().unwrap(), ContractError::ValidationError { .. }));
+
+        app.update_block(|block| block.time = block.time.plus_seconds(3600));
+        let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert!(resp.is_available);
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod test_text_records {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_text_records() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let controller = Addr::unchecked("controller");
+
+        //register a name
+        let name = "example".to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some("example_site_cw_address".into_bech32().to_string()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //only the owner can set text records, not even the controller
+        let set_controller_msg = ExecuteMsg::SetController(SetControllerMsg {
+            name: name.parse().unwrap(),
+            controller: Some(controller.clone()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_controller_msg, &[])
+            .unwrap();
+        let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+            value: "@example".to_string(),
+        });
+        let err = app
+            .execute_contract(controller.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "avatar".to_string(),
+            value: "https://example.com/avatar.png".to_string(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::TextRecord {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, Some("@example".to_string()));
+
+        let query_msg = msg::QueryMsg::TextRecords {
+            name: name.parse().unwrap(),
+        };
+        let resp: TextRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let keys: Vec<String> = resp.text_records.into_iter().map(|r| r.key).collect();
+        assert_eq!(keys, vec!["avatar", "twitter"]);
+
+        //keys and values are length-limited
+        let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "".to_string(),
+            value: "value".to_string(),
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Text record key must be between 1 and 64 characters".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //the number of text records per name is capped
+        for i in 2..32 {
+            let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+                name: name.parse().unwrap(),
+                key: format!("key{}", i),
+                value: "value".to_string(),
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+                .unwrap();
+        }
+        let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "one_too_many".to_string(),
+            value: "value".to_string(),
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Names can have at most 32 text records".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //overwriting an existing key is still allowed at the cap
+        let set_msg = ExecuteMsg::SetTextRecord(SetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+            value: "@example2".to_string(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        //unset a text record
+        let unset_msg = ExecuteMsg::UnsetTextRecord(UnsetTextRecordMsg {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &unset_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::TextRecord {
+            name: name.parse().unwrap(),
+            key: "twitter".to_string(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod test_update_config {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use msg::*;
+
+    #[test]
+    fn test_exec_update_config_and_contract_targets() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.allowed_hrps = vec!["Cosmwasm".to_string()];
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+
+        //the instantiator is the admin and prefixes are normalized
+        let query_msg = msg::QueryMsg::Config {};
+        let resp: ConfigResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.0.admin, Some(owner_address.clone()));
+        assert_eq!(resp.0.allowed_hrps, vec!["cosmwasm".to_string()]);
+        assert!(!resp.0.verify_contract_targets);
+
+        let register = |app: &mut cw_multi_test::App, name: &str, address: String| {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(address),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            app.execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+        };
+
+        //targets on networks that aren't allowed are rejected
+        let stars_address = "example_site_cw_address".into_bech32_with_prefix("stars").to_string();
+        let err = register(&mut app, "example", stars_address.clone()).unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Network prefix stars is not allowed".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //account addresses can't be targets
+        let account_address =
+            bech32::encode::<bech32::Bech32>(bech32::Hrp::parse("cosmwasm").unwrap(), &[1u8; 20]).unwrap();
+        let err = register(&mut app, "example", account_address.clone()).unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: format!("{} is not a contract address", account_address),
+            },
+            err.downcast().unwrap()
+        );
+
+        //only the admin can update the config
+        let update_config_msg = ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            admin: None,
+            allowed_hrps: Some(vec!["cosmwasm".to_string(), "stars".to_string()]),
+            verify_contract_targets: None,
+            probe_render_targets: None,
+            require_target_admin: None,
+            gifting: None,
+            release_cooldown: None,
+            referral_share: None,
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_config_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "Sender is not the admin".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(owner_address.clone(), addr.clone(), &update_config_msg, &[])
+            .unwrap();
+
+        //the newly allowed network can be targeted
+        let _resp = register(&mut app, "example", stars_address.clone()).unwrap();
+
+        //targets on other chains can't be probed for render support
+        let update_config_msg = ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            admin: None,
+            allowed_hrps: None,
+            verify_contract_targets: None,
+            probe_render_targets: Some(true),
+            require_target_admin: None,
+            gifting: None,
+            release_cooldown: None,
+            referral_share: None,
+        });
+        let _resp = app
+            .execute_contract(owner_address.clone(), addr.clone(), &update_config_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::Config {};
+        let resp: ConfigResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert!(resp.0.probe_render_targets);
+        let _resp = register(&mut app, "example2", stars_address).unwrap();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: "example2".to_string(),
+        };
+        let resp: models::PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.render_capable, None);
+
+        //invalid prefixes are rejected
+        let update_config_msg = ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            admin: None,
+            allowed_hrps: Some(vec!["not a prefix".to_string()]),
+            verify_contract_targets: None,
+            probe_render_targets: None,
+            require_target_admin: None,
+            gifting: None,
+            release_cooldown: None,
+            referral_share: None,
+        });
+        let err = app
+            .execute_contract(owner_address.clone(), addr.clone(), &update_config_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "not a prefix is not a valid bech32 prefix".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //contracts migrated from before the admin role existed get the given
+        //admin, or else their wasm admin
+        state::ADMIN.remove(app.contract_storage_mut(&addr).as_mut());
+        let query_msg = msg::QueryMsg::Config {};
+        let resp: ConfigResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.0.admin, None);
+        let code_id = app.wrap().query_wasm_contract_info(&addr).unwrap().code_id;
+        let _resp = app
+            .migrate_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &MigrateMsg {
+                    admin: None,
+                    limit: None,
+                },
+                code_id,
+            )
+            .unwrap();
+        let resp: ConfigResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.0.admin, Some(owner_address.clone()));
+        let migrate_msg = MigrateMsg {
+            admin: Some(name_owner.clone()),
+            limit: None,
+        };
+        let _resp = app
+            .migrate_contract(owner_address.clone(), addr.clone(), &migrate_msg, code_id)
+            .unwrap();
+        let resp: ConfigResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.0.admin, Some(name_owner.clone()));
+    }
+
+    #[test]
+    fn test_instantiate_defaults() {
+        let owner_str = "owner".to_string();
+        let mut app = def_app(owner_str.clone(), "name_owner".to_string(), 1000);
+        let code_id = app.store_code(dns_contract());
+        let owner_address = Addr::unchecked(owner_str.clone());
+
+        //settings added since the first release can be left out
+        let inst_msg = serde_json::json!({
+            "price": { "token": { "denom": "juno" }, "amount": "1" },
+            "fee_recipient": "fee_recipient",
+            "max_name_len": 10,
+        });
+        let addr = app
+            .instantiate_contract(code_id, owner_address.clone(), &inst_msg, &[], "test", None)
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::Config {};
+        let resp: ConfigResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.0.allowed_hrps, Vec::<String>::new());
+        assert!(!resp.0.verify_contract_targets);
+        assert!(!resp.0.probe_render_targets);
+        assert!(!resp.0.require_target_admin);
+        assert_eq!(resp.0.gifting, models::GiftingPolicy::Anyone);
+        assert_eq!(resp.0.release_cooldown, DEFAULT_RELEASE_COOLDOWN);
+        assert_eq!(resp.0.referral_share, 0);
+    }
+}
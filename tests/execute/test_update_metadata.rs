@@ -2,13 +2,12 @@
 mod test_update_metadata {
     use crate::test_utils::*;
     use cosmwasm_std::coins;
-    use cosmwasm_std::{Addr, Uint128};
+    use cosmwasm_std::Addr;
     use cw_multi_test::{App, Contract, ContractWrapper, Executor, IntoBech32};
     use cw_web31_dns::*;
     use error::*;
     use models::*;
     use msg::*;
-    use token::TokenAmount;
 
     #[test]
     fn test_exec_update_metadata() {
@@ -16,30 +15,8 @@ mod test_update_metadata {
         let owner_str = "owner".to_string();
         let amount = 1000u128;
         let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
-        let contract = dns_contract();
-        let code_id = app.store_code(contract);
-
         let owner_address = Addr::unchecked(owner_str.clone());
-        let fee_recipient = Addr::unchecked("fee_recipient");
-        let inst_msg = InstantiateMsg {
-            price: TokenAmount {
-                token: token::Token::Denom("juno".to_string()),
-                amount: Uint128::from(1u128),
-            },
-            fee_recipient,
-            max_name_len: 10,
-        };
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                owner_address.clone(),
-                &inst_msg,
-                &[],
-                "test",
-                Some(owner_address.to_string()),
-            )
-            .unwrap();
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
 
         let name_owner = Addr::unchecked(name_owner_str.clone());
         //register a name
@@ -48,9 +25,12 @@ mod test_update_metadata {
         let site_address = "example_site_cw_address".into_bech32().to_string();
         let reg_msg = ExecuteMsg::Register(RegisterMsg {
             owner: name_owner.clone(),
-            name: name.clone(),
-            address: site_address,
+            name: name.parse().unwrap(),
+            address: Some(site_address),
+            content_hash: None,
             meta: None,
+            voucher: None,
+            referrer: None,
         });
         let _resp = app
             .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
@@ -68,13 +48,14 @@ mod test_update_metadata {
 
         //update the metadata
         let update_msg = UpdateMetadataMsg {
-            name: name.to_string(),
+            name: name.parse().unwrap(),
             meta: NameMetadata {
                 title: Some("example_title".to_string()),
                 description: Some("example_description".to_string()),
                 favicon: None,
                 logo: None,
                 keywords: None,
+                ..Default::default()
             },
         };
         let _resp = app
@@ -98,13 +79,14 @@ mod test_update_metadata {
 
         //update the metadata
         let update_msg = UpdateMetadataMsg {
-            name: name.to_string(),
+            name: name.parse().unwrap(),
             meta: NameMetadata {
                 title: None,
                 description: None,
-                favicon: Some(ImageAsset::Url("example_favicon_url".to_string())),
-                logo: Some(ImageAsset::Url("example_logo_url".to_string())),
+                favicon: Some(ImageAsset::Url("https://example.com/favicon.ico".to_string())),
+                logo: Some(ImageAsset::Url("https://example.com/logo.png".to_string())),
                 keywords: Some(vec!["example_keyword1".to_string(), "example_keyword2".to_string()]),
+                ..Default::default()
             },
         };
         let _resp = app
@@ -124,9 +106,12 @@ mod test_update_metadata {
         assert_eq!(resp.meta.description, Some("example_description".to_string()));
         assert_eq!(
             resp.meta.favicon,
-            Some(ImageAsset::Url("example_favicon_url".to_string()))
+            Some(ImageAsset::Url("https://example.com/favicon.ico".to_string()))
+        );
+        assert_eq!(
+            resp.meta.logo,
+            Some(ImageAsset::Url("https://example.com/logo.png".to_string()))
         );
-        assert_eq!(resp.meta.logo, Some(ImageAsset::Url("example_logo_url".to_string())));
         assert_eq!(
             resp.meta.keywords,
             Some(vec!["example_keyword1".to_string(), "example_keyword2".to_string()])
@@ -134,13 +119,14 @@ mod test_update_metadata {
 
         //clean the keywords metadata
         let update_msg = UpdateMetadataMsg {
-            name: name.to_string(),
+            name: name.parse().unwrap(),
             meta: NameMetadata {
                 title: None,
                 description: None,
                 favicon: None,
                 logo: None,
                 keywords: Some(vec![]),
+                ..Default::default()
             },
         };
         let _resp = app
@@ -160,20 +146,24 @@ mod test_update_metadata {
         assert_eq!(resp.meta.description, Some("example_description".to_string()));
         assert_eq!(
             resp.meta.favicon,
-            Some(ImageAsset::Url("example_favicon_url".to_string()))
+            Some(ImageAsset::Url("https://example.com/favicon.ico".to_string()))
+        );
+        assert_eq!(
+            resp.meta.logo,
+            Some(ImageAsset::Url("https://example.com/logo.png".to_string()))
         );
-        assert_eq!(resp.meta.logo, Some(ImageAsset::Url("example_logo_url".to_string())));
         assert_eq!(resp.meta.keywords, Some(vec![]));
 
         //check error when updating metadata of non owner
         let update_msg = UpdateMetadataMsg {
-            name: name.to_string(),
+            name: name.parse().unwrap(),
             meta: NameMetadata {
                 title: None,
                 description: None,
                 favicon: None,
                 logo: None,
                 keywords: Some(vec![]),
+                ..Default::default()
             },
         };
         let err = app
@@ -187,7 +177,7 @@ mod test_update_metadata {
 
         assert_eq!(
             ContractError::NotAuthorized {
-                reason: "You are not the owner of this name".to_string(),
+                reason: "You are not the controller of this name".to_string(),
             },
             err.downcast().unwrap()
         );
@@ -200,20 +190,24 @@ mod test_update_metadata {
         assert_eq!(resp.meta.description, Some("example_description".to_string()));
         assert_eq!(
             resp.meta.favicon,
-            Some(ImageAsset::Url("example_favicon_url".to_string()))
+            Some(ImageAsset::Url("https://example.com/favicon.ico".to_string()))
+        );
+        assert_eq!(
+            resp.meta.logo,
+            Some(ImageAsset::Url("https://example.com/logo.png".to_string()))
         );
-        assert_eq!(resp.meta.logo, Some(ImageAsset::Url("example_logo_url".to_string())));
         assert_eq!(resp.meta.keywords, Some(vec![]));
 
         //check title length validation
         let update_msg = UpdateMetadataMsg {
-            name: name.to_string(),
+            name: name.parse().unwrap(),
             meta: NameMetadata {
                 title: Some("a".repeat(NameMetadata::MAX_TITLE_LEN + 1)),
                 description: None,
                 favicon: None,
                 logo: None,
                 keywords: None,
+                ..Default::default()
             },
         };
         let err = app
@@ -240,9 +234,377 @@ mod test_update_metadata {
         assert_eq!(resp.meta.description, Some("example_description".to_string()));
         assert_eq!(
             resp.meta.favicon,
-            Some(ImageAsset::Url("example_favicon_url".to_string()))
+            Some(ImageAsset::Url("https://example.com/favicon.ico".to_string()))
+        );
+        assert_eq!(
+            resp.meta.logo,
+            Some(ImageAsset::Url("https://example.com/logo.png".to_string()))
         );
-        assert_eq!(resp.meta.logo, Some(ImageAsset::Url("example_logo_url".to_string())));
         assert_eq!(resp.meta.keywords, Some(vec![]));
     }
+    #[test]
+    fn test_exec_update_rich_metadata() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        //register a name
+        let name = "example".to_string();
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //update the social links, colors, banner, locale and canonical url
+        let update_msg = UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                social_links: Some(vec![SocialLink {
+                    platform: "twitter".to_string(),
+                    url: "https://twitter.com/example".to_string(),
+                }]),
+                theme_color: Some("#1a2b3c".to_string()),
+                background_color: Some("#fff".to_string()),
+                banner: Some(ImageAsset::Url("ipfs://example_banner".to_string())),
+                locale: Some("pt-BR".to_string()),
+                canonical_url: Some("https://example.com".to_string()),
+                ..Default::default()
+            },
+        };
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &ExecuteMsg::UpdateMetadata(update_msg),
+                &[],
+            )
+            .unwrap();
+
+        //inline SVGs may reference their own fragments
+        let svg = concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><defs><linearGradient id="g"/></defs>"#,
+            r##"<rect fill="url(#g)"/><use href="#g"/></svg>"##,
+        );
+        let update_msg = UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                logo: Some(ImageAsset::Svg(svg.to_string())),
+                ..Default::default()
+            },
+        };
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &ExecuteMsg::UpdateMetadata(update_msg),
+                &[],
+            )
+            .unwrap();
+
+        //fields missing from an update are left unchanged
+        let update_msg = UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                theme_color: Some("#000000".to_string()),
+                ..Default::default()
+            },
+        };
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &ExecuteMsg::UpdateMetadata(update_msg),
+                &[],
+            )
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::NameRecord { contract: name.clone() };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.meta.social_links.map(|links| links.len()), Some(1));
+        assert_eq!(resp.meta.theme_color, Some("#000000".to_string()));
+        assert_eq!(resp.meta.background_color, Some("#fff".to_string()));
+        assert_eq!(
+            resp.meta.banner,
+            Some(ImageAsset::Url("ipfs://example_banner".to_string()))
+        );
+        assert_eq!(resp.meta.locale, Some("pt-BR".to_string()));
+        assert_eq!(resp.meta.canonical_url, Some("https://example.com".to_string()));
+
+        //each field is validated
+        let invalid_metas = vec![
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Url("http://example.com/logo.png".to_string())),
+                    ..Default::default()
+                },
+                "Image URL must use one of https://, ipfs://, ar:// and be at most 512 characters",
+            ),
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Svg("<svg><script>alert(1)</script></svg>".to_string())),
+                    ..Default::default()
+                },
+                "Invalid SVG: <script> is not allowed",
+            ),
+            (
+                NameMetadata {
+                    favicon: Some(ImageAsset::Svg("<svg onload=\"alert(1)\"></svg>".to_string())),
+                    ..Default::default()
+                },
+                "Invalid SVG: event handler attributes are not allowed",
+            ),
+            (
+                NameMetadata {
+                    banner: Some(ImageAsset::Svg(
+                        "<svg><use href=\"https://example.com/track.svg#g\"/></svg>".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                "Invalid SVG: external references are not allowed",
+            ),
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Svg(
+                        "<svg><animate attributeName=\"href\" to=\"&#106;avascript:alert(1)\"/></svg>".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                "Invalid SVG: <animate> is not allowed",
+            ),
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Svg(
+                        "<svg><set attributeName=\"href\" to=\"javascript:alert(1)\"/></svg>".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                "Invalid SVG: <set> is not allowed",
+            ),
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Svg(
+                        "<svg><foreignObject><iframe src=\"https://example.com\"/></foreignObject></svg>".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                "Invalid SVG: <foreignobject> is not allowed",
+            ),
+            (
+                NameMetadata {
+                    favicon: Some(ImageAsset::Svg("<svg\x0conload=\"alert(1)\"></svg>".to_string())),
+                    ..Default::default()
+                },
+                "Invalid SVG: malformed <svg> tag",
+            ),
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Svg(
+                        "<svg><use href=\"&#106;avascript:alert(1)\"/></svg>".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                "Invalid SVG: external references are not allowed",
+            ),
+            (
+                NameMetadata {
+                    logo: Some(ImageAsset::Svg(
+                        "<svg><rect fill=\"u&#x72;l(https://example.com/track)\"/></svg>".to_string(),
+                    )),
+                    ..Default::default()
+                },
+                "Invalid SVG: external references are not allowed",
+            ),
+            (
+                NameMetadata {
+                    banner: Some(ImageAsset::Svg(format!("<svg>{}</svg>", "a".repeat(16 * 1024)))),
+                    ..Default::default()
+                },
+                "Invalid SVG: must be at most 16384 bytes",
+            ),
+            (
+                NameMetadata {
+                    theme_color: Some("red".to_string()),
+                    ..Default::default()
+                },
+                "Theme color must be a hex color like #1a2b3c",
+            ),
+            (
+                NameMetadata {
+                    locale: Some("english".to_string()),
+                    ..Default::default()
+                },
+                "english is not a valid locale",
+            ),
+            (
+                NameMetadata {
+                    canonical_url: Some("http://example.com".to_string()),
+                    ..Default::default()
+                },
+                "Canonical URL must be an https URL of at most 256 characters",
+            ),
+            (
+                NameMetadata {
+                    social_links: Some(vec![SocialLink {
+                        platform: "".to_string(),
+                        url: "https://example.com".to_string(),
+                    }]),
+                    ..Default::default()
+                },
+                "Social platform must be between 1 and 32 characters",
+            ),
+        ];
+        for (meta, reason) in invalid_metas {
+            let update_msg = UpdateMetadataMsg {
+                name: name.parse().unwrap(),
+                meta,
+            };
+            let err = app
+                .execute_contract(
+                    name_owner.clone(),
+                    addr.clone(),
+                    &ExecuteMsg::UpdateMetadata(update_msg),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::ValidationError {
+                    reason: reason.to_string(),
+                },
+                err.downcast().unwrap()
+            );
+        }
+    }
+    #[test]
+    fn test_exec_clear_and_replace_metadata() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        //register a name
+        let name = "example".to_string();
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        let update_msg = UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                title: Some("example_title".to_string()),
+                description: Some("example_description".to_string()),
+                keywords: Some(vec!["example_keyword".to_string()]),
+                ..Default::default()
+            },
+        };
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &ExecuteMsg::UpdateMetadata(update_msg),
+                &[],
+            )
+            .unwrap();
+
+        //clear individual fields
+        let clear_msg = ClearMetadataFieldsMsg {
+            name: name.parse().unwrap(),
+            fields: vec![MetadataField::Title, MetadataField::Keywords],
+        };
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &ExecuteMsg::ClearMetadataFields(clear_msg),
+                &[],
+            )
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::NameRecord { contract: name.clone() };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.meta.title, None);
+        assert_eq!(resp.meta.description, Some("example_description".to_string()));
+        assert_eq!(resp.meta.keywords, None);
+
+        //cleared keywords are dropped from the keyword index
+        let query_msg = msg::QueryMsg::NamesByKeyword(NamesByKeywordQueryMsg {
+            keyword: "example_keyword".to_string(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 0);
+
+        //replacing the metadata clears every field not given
+        let replace_msg = UpdateMetadataMsg {
+            name: name.parse().unwrap(),
+            meta: NameMetadata {
+                title: Some("new_title".to_string()),
+                ..Default::default()
+            },
+        };
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &ExecuteMsg::ReplaceMetadata(replace_msg),
+                &[],
+            )
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::NameRecord { contract: name.clone() };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(
+            resp.meta,
+            NameMetadata {
+                title: Some("new_title".to_string()),
+                ..Default::default()
+            }
+        );
+
+        //only the controller can clear metadata fields
+        let clear_msg = ClearMetadataFieldsMsg {
+            name: name.parse().unwrap(),
+            fields: vec![MetadataField::Title],
+        };
+        let err = app
+            .execute_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &ExecuteMsg::ClearMetadataFields(clear_msg),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the controller of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+    }
 }
@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod test_vouchers {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+    use token::TokenAmount;
+
+    #[test]
+    fn test_vouchers_and_referrals() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let fee_recipient = Addr::unchecked("fee_recipient");
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.price = TokenAmount {
+                token: token::Token::Denom("juno".to_string()),
+                amount: Uint128::from(10u128),
+            };
+            inst_msg.fee_recipient = fee_recipient.clone();
+            inst_msg.referral_share = 20;
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let referrer = Addr::unchecked("referrer");
+        let reg_msg = |name: &str, voucher: Option<&str>, referrer: Option<&Addr>| {
+            ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some("example_site_cw_address".into_bech32().to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: voucher.map(String::from),
+                referrer: referrer.cloned(),
+            })
+        };
+        let create_msg = |code: &str, discount: Discount, expires_at, max_name_len| {
+            ExecuteMsg::CreateVoucher(CreateVoucherMsg {
+                code: code.to_string(),
+                discount,
+                max_uses: 1,
+                expires_at,
+                min_name_len: None,
+                max_name_len,
+            })
+        };
+
+        //only the admin can create vouchers
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &create_msg("HALF", Discount::Percent(50), None, None),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "Sender is not the admin".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &create_msg("HALF", Discount::Percent(50), None, None),
+                &[],
+            )
+            .unwrap();
+        let query_msg = msg::QueryMsg::Voucher {
+            code: "HALF".to_string(),
+        };
+        let resp: VoucherQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.uses_left, 1);
+        assert!(!resp.is_expired);
+
+        //the discounted price must be paid
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("example", Some("HALF"), Some(&referrer)),
+                &coins(10, "juno"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientFunds { exp_amount: 5 },
+            err.downcast().unwrap()
+        );
+
+        //the referrer is paid their share and the fee recipient the rest
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("example", Some("HALF"), Some(&referrer)),
+                &coins(5, "juno"),
+            )
+            .unwrap();
+        assert_eq!(app.wrap().query_balance(&referrer, "juno").unwrap().amount.u128(), 1);
+        assert_eq!(
+            app.wrap().query_balance(&fee_recipient, "juno").unwrap().amount.u128(),
+            4
+        );
+        let query_msg = msg::QueryMsg::ReferralEarnings {
+            referrer: referrer.clone(),
+        };
+        let resp: ReferralEarningsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(
+            resp.earnings,
+            vec![TokenAmount {
+                token: token::Token::Denom("juno".to_string()),
+                amount: Uint128::from(1u128),
+            }]
+        );
+
+        //the voucher is used up
+        let query_msg = msg::QueryMsg::Voucher {
+            code: "HALF".to_string(),
+        };
+        let resp: VoucherQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.uses_left, 0);
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("example2", Some("HALF"), None),
+                &coins(5, "juno"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Voucher has been used up".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //names can't be referred by their registrant
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("example2", None, Some(&name_owner)),
+                &coins(10, "juno"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "You may not refer yourself".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //free names for short names only, nothing needs to be paid
+        let _resp = app
+            .execute_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &create_msg("FREE", Discount::Percent(100), None, Some(4)),
+                &[],
+            )
+            .unwrap();
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("toolong", Some("FREE"), None),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Voucher does not apply to names of this length".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("free", Some("FREE"), None),
+                &coins(10, "juno"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "No payment is due, don't attach funds".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("free", Some("FREE"), None),
+                &[],
+            )
+            .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(&fee_recipient, "juno").unwrap().amount.u128(),
+            4
+        );
+
+        //expired vouchers can't be redeemed
+        let expires_at = app.block_info().time.plus_seconds(60);
+        let _resp = app
+            .execute_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &create_msg("SOON", Discount::Fixed(Uint128::from(3u128)), Some(expires_at), None),
+                &[],
+            )
+            .unwrap();
+        app.update_block(|block| block.time = block.time.plus_seconds(60));
+        let err = app
+            .execute_contract(
+                name_owner.clone(),
+                addr.clone(),
+                &reg_msg("example2", Some("SOON"), None),
+                &coins(7, "juno"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Voucher has expired".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //revoked vouchers are gone
+        let _resp = app
+            .execute_contract(
+                owner_address.clone(),
+                addr.clone(),
+                &ExecuteMsg::RevokeVoucher(RevokeVoucherMsg {
+                    code: "SOON".to_string(),
+                }),
+                &[],
+            )
+            .unwrap();
+        let query_msg = msg::QueryMsg::Voucher {
+            code: "SOON".to_string(),
+        };
+        let resp: Result<VoucherQueryResponse, _> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
+        assert!(resp.is_err());
+    }
+}
@@ -1,2 +1,10 @@
 // Add query tests file here
+pub mod test_image_asset;
+pub mod test_is_available;
 pub mod test_name_records;
+pub mod test_names_by_contract;
+pub mod test_names_by_keyword;
+pub mod test_names_by_owner;
+pub mod test_primary_name;
+pub mod test_resolve_address;
+pub mod test_search_names;
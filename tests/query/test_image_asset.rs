@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod test_image_asset {
+    use crate::test_utils::*;
+    use cosmwasm_std::Addr;
+    use cosmwasm_std::{coins, Binary, StdError};
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_image_asset() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let png = Binary::from(b"\x89PNG\r\n\x1a\nexample".to_vec());
+
+        //register a name with a PNG favicon and a URL logo
+        let name = "example".to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some("example_site_cw_address".into_bech32().to_string()),
+            content_hash: None,
+            meta: Some(NameMetadata {
+                favicon: Some(ImageAsset::DataUri {
+                    mime_type: "image/png".to_string(),
+                    data: png.clone(),
+                }),
+                logo: Some(ImageAsset::Url("https://example.com/logo.png".to_string())),
+                banner: Some(ImageAsset::Svg("<svg></svg>".to_string())),
+                ..Default::default()
+            }),
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //on-chain images are returned as raw bytes
+        let query_msg = msg::QueryMsg::ImageAsset {
+            name: name.parse().unwrap(),
+            kind: ImageAssetKind::Favicon,
+        };
+        let resp: ImageAssetQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.content_type, "image/png");
+        assert_eq!(resp.data, png);
+
+        let query_msg = msg::QueryMsg::ImageAsset {
+            name: name.parse().unwrap(),
+            kind: ImageAssetKind::Banner,
+        };
+        let resp: ImageAssetQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.content_type, "image/svg+xml");
+        assert_eq!(resp.data, Binary::from(b"<svg></svg>".to_vec()));
+
+        //assets hosted at a URL are not returned
+        let query_msg = msg::QueryMsg::ImageAsset {
+            name: name.parse().unwrap(),
+            kind: ImageAssetKind::Logo,
+        };
+        let err: Result<ImageAssetQueryResponse, StdError> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
+        assert!(err.is_err());
+
+        //MIME types and sizes are validated
+        for (mime_type, data, reason) in [
+            (
+                "text/html",
+                png.clone(),
+                "Image MIME type must be one of image/png, image/jpeg, image/gif, image/webp, image/x-icon, \
+                 image/vnd.microsoft.icon",
+            ),
+            (
+                "image/png",
+                Binary::from(vec![0u8; ImageAsset::MAX_DATA_LEN + 1]),
+                "Image data must be between 1 and 32768 bytes",
+            ),
+        ] {
+            let update_msg = ExecuteMsg::UpdateMetadata(UpdateMetadataMsg {
+                name: name.parse().unwrap(),
+                meta: NameMetadata {
+                    favicon: Some(ImageAsset::DataUri {
+                        mime_type: mime_type.to_string(),
+                        data,
+                    }),
+                    ..Default::default()
+                },
+            });
+            let err = app
+                .execute_contract(name_owner.clone(), addr.clone(), &update_msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                ContractError::ValidationError {
+                    reason: reason.to_string(),
+                },
+                err.downcast().unwrap()
+            );
+        }
+    }
+}
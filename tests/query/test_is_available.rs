@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod test_is_available {
+    use crate::test_utils::*;
+    use cosmwasm_std::{coins, StdError};
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use msg::*;
+    use token::TokenAmount;
+
+    #[test]
+    fn test_query_is_available_and_batch_lookups() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let price = TokenAmount {
+            token: token::Token::Denom("juno".to_string()),
+            amount: Uint128::from(1u128),
+        };
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.price = price.clone();
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "example".parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //a free name is available at the configured price
+        let query_msg = msg::QueryMsg::IsAvailable {
+            name: "Free-Name".to_string(),
+        };
+        let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "free-name");
+        assert!(resp.is_available);
+        assert_eq!(resp.reason, None);
+        assert_eq!(resp.price, price);
+
+        //a registered name isn't available
+        let query_msg = msg::QueryMsg::IsAvailable {
+            name: "EXAMPLE".to_string(),
+        };
+        let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert!(!resp.is_available);
+        assert_eq!(
+            resp.reason,
+            Some(
+                ContractError::NameExists {
+                    name: "example".to_string()
+                }
+                .to_string()
+            )
+        );
+
+        //invalid names aren't available and can't be registered
+        for name in ["", "way-too-long-name", "-example", "exa_mple", "exa.mple"] {
+            let query_msg = msg::QueryMsg::IsAvailable { name: name.to_string() };
+            let resp: IsAvailableQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            assert!(!resp.is_available);
+            assert!(resp.reason.unwrap().starts_with("ValidationError:"));
+
+            //names failing the contract's own length limit still parse into a Name
+            let name = match name.parse::<name::Name>() {
+                Ok(name) => name,
+                Err(err) => {
+                    assert!(matches!(err, ContractError::ValidationError { .. }));
+                    continue;
+                },
+            };
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name,
+                address: Some(site_address.clone()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let err = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap_err();
+            assert!(matches!(err.downcast().unwrap(), ContractError::ValidationError { .. }));
+        }
+
+        //look up several names and addresses at once
+        let query_msg = msg::QueryMsg::BatchNameRecords {
+            names: vec!["example".to_string(), "missing".to_string(), site_address.clone()],
+        };
+        let resp: BatchNameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 3);
+        assert_eq!(resp.name_records[0].as_ref().unwrap().cannonical_name, "example");
+        assert_eq!(resp.name_records[1], None);
+        assert_eq!(resp.name_records[2].as_ref().unwrap().cannonical_name, "example");
+
+        let query_msg = msg::QueryMsg::BatchResolve {
+            names_or_addresses: vec!["example".to_string(), "missing".to_string(), site_address.clone()],
+        };
+        let resp: BatchResolveQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(
+            resp.contracts,
+            vec![Some(site_address.clone()), None, Some(site_address.clone())]
+        );
+
+        //batches are bounded
+        let query_msg = msg::QueryMsg::BatchResolve {
+            names_or_addresses: vec!["example".to_string(); query::batch_name_records::MAX_BATCH_QUERY_SIZE + 1],
+        };
+        let resp: Result<BatchResolveQueryResponse, StdError> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
+        assert!(resp.unwrap_err().to_string().contains("TooManyRecords:"));
+    }
+}
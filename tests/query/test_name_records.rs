@@ -1,14 +1,13 @@
 #[cfg(test)]
 mod test_name_records {
     use crate::test_utils::*;
+    use cosmwasm_std::Addr;
     use cosmwasm_std::{coins, StdError};
-    use cosmwasm_std::{Addr, Uint128};
-    use cw_multi_test::{App, Contract, ContractWrapper, Executor, IntoBech32};
+    use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor, IntoBech32};
     use cw_web31_dns::*;
     use error::*;
     use models::*;
     use msg::*;
-    use token::TokenAmount;
 
     #[test]
     fn test_query_name_records() {
@@ -16,30 +15,10 @@ mod test_name_records {
         let owner_str = "owner".to_string();
         let amount = 1000u128;
         let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
-        let contract = dns_contract();
-        let code_id = app.store_code(contract);
-
         let owner_address = Addr::unchecked(owner_str.clone());
-        let fee_recipient = Addr::unchecked("fee_recipient");
-        let inst_msg = InstantiateMsg {
-            price: TokenAmount {
-                token: token::Token::Denom("juno".to_string()),
-                amount: Uint128::from(1u128),
-            },
-            fee_recipient,
-            max_name_len: 10,
-        };
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                owner_address.clone(),
-                &inst_msg,
-                &[],
-                "test",
-                Some(owner_address.to_string()),
-            )
-            .unwrap();
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.allowed_hrps = vec!["cosmwasm".to_string(), "juno".to_string(), "osmo".to_string()];
+        });
 
         let name_owner = Addr::unchecked(name_owner_str.clone());
         //register a name
@@ -53,15 +32,19 @@ mod test_name_records {
             println!("site_address: {:?}", site_address);
             let reg_msg = ExecuteMsg::Register(RegisterMsg {
                 owner: name_owner.clone(),
-                name: name.clone(),
-                address: site_address.clone(),
+                name: name.parse().unwrap(),
+                address: Some(site_address.clone()),
+                content_hash: None,
                 meta: Some(models::NameMetadata {
-                    title: Some(format!("example_title{}",i)),
-                    description: Some(format!("example_description{}",i)),
-                    favicon: Some(ImageAsset::Url(format!("example_favicon_url{}",i))),
-                    logo: Some(ImageAsset::Url(format!("example_logo_url{}",i))),
-                    keywords: Some(vec![format!("example_keyword{}",i)]),
+                    title: Some(format!("example_title{}", i)),
+                    description: Some(format!("example_description{}", i)),
+                    favicon: Some(ImageAsset::Url(format!("https://example.com/favicon{}.ico", i))),
+                    logo: Some(ImageAsset::Url(format!("https://example.com/logo{}.png", i))),
+                    keywords: Some(vec![format!("example_keyword{}", i)]),
+                    ..Default::default()
                 }),
+                voucher: None,
+                referrer: None,
             });
             let _resp = app
                 .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
@@ -72,6 +55,7 @@ mod test_name_records {
             limit: 10,
             cursor: None,
             network_prefix: Some("juno".to_string()),
+            ..Default::default()
         });
         let resp = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         let name_records_msg: NameRecordsQueryResponse = resp.unwrap();
@@ -87,14 +71,15 @@ mod test_name_records {
             let site_address = format!("example_site_address{}", i)
                 .into_bech32_with_prefix(&network_den)
                 .to_string();
-            assert_eq!(name_records_msg.name_records[i].cannonical_name, name);
-            assert_eq!(name_records_msg.name_records[i].contract, site_address);
+            assert_eq!(name_records_msg.name_records[i].cannonical_name.as_str(), name);
+            assert_eq!(name_records_msg.name_records[i].contract, Some(site_address));
         }
         //query the name metadata with cursor expecting an empty array
         let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
             limit: 10,
             cursor: next_cursor,
             network_prefix: None,
+            ..Default::default()
         });
         let resp = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         let name_records_msg: NameRecordsQueryResponse = resp.unwrap();
@@ -110,6 +95,7 @@ mod test_name_records {
             limit: 5,
             cursor: None,
             network_prefix: None,
+            ..Default::default()
         });
         let resp = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         let name_records_msg: NameRecordsQueryResponse = resp.unwrap();
@@ -125,6 +111,7 @@ mod test_name_records {
             limit: 10,
             cursor: next_cursor,
             network_prefix: None,
+            ..Default::default()
         });
         let resp = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         let name_records_msg: NameRecordsQueryResponse = resp.unwrap();
@@ -139,8 +126,8 @@ mod test_name_records {
             let site_address = format!("example_site_address{}", i)
                 .into_bech32_with_prefix(&network_den)
                 .to_string();
-            assert_eq!(name_records_msg.name_records[i - 5].cannonical_name, name);
-            assert_eq!(name_records_msg.name_records[i - 5].contract, site_address);
+            assert_eq!(name_records_msg.name_records[i - 5].cannonical_name.as_str(), name);
+            assert_eq!(name_records_msg.name_records[i - 5].contract, Some(site_address));
         }
 
         // query the names with another network_den expecting 0 names
@@ -148,6 +135,7 @@ mod test_name_records {
             limit: 10,
             cursor: None,
             network_prefix: Some("osmo".to_string()),
+            ..Default::default()
         });
         let resp = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         let name_records_msg: NameRecordsQueryResponse = resp.unwrap();
@@ -163,6 +151,7 @@ mod test_name_records {
             limit: 31,
             cursor: None,
             network_prefix: None,
+            ..Default::default()
         });
         let resp: Result<NameRecordsQueryResponse, StdError> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         println!("resp: {:?}", resp);
@@ -174,8 +163,9 @@ mod test_name_records {
         //query the name metadata with error NotFound
         let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
             limit: 10,
-            cursor: Some("not_added".to_string()),
+            cursor: Some("notadded".parse().unwrap()),
             network_prefix: None,
+            ..Default::default()
         });
         let resp: Result<NameRecordsQueryResponse, StdError> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
         println!("resp: {:?}", resp);
@@ -184,4 +174,193 @@ mod test_name_records {
         let err_msg = resp.err().unwrap().to_string();
         assert!(err_msg.contains("NotFound:"));
     }
+
+    #[test]
+    fn test_query_name_records_by_network() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.allowed_hrps = vec!["cosmwasm".to_string(), "juno".to_string(), "osmo".to_string()];
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        // register 10 names, alternating between juno and osmo contracts
+        for i in 0..10 {
+            let network_den = if i % 2 == 0 { "juno" } else { "osmo" };
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: format!("example{}", i).parse().unwrap(),
+                address: Some(
+                    format!("example_site_address{}", i)
+                        .into_bech32_with_prefix(network_den)
+                        .to_string(),
+                ),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        //query the first page of osmo names
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 3,
+            cursor: None,
+            network_prefix: Some("osmo".to_string()),
+            ..Default::default()
+        });
+        let name_records_msg: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = name_records_msg
+            .name_records
+            .iter()
+            .map(|r| r.cannonical_name.to_string())
+            .collect();
+        assert_eq!(names, vec!["example1", "example3", "example5"]);
+        assert_eq!(name_records_msg.next_cursor, Some("example5".parse().unwrap()));
+
+        //query the next page of osmo names
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 3,
+            cursor: name_records_msg.next_cursor,
+            network_prefix: Some("osmo".to_string()),
+            ..Default::default()
+        });
+        let name_records_msg: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = name_records_msg
+            .name_records
+            .iter()
+            .map(|r| r.cannonical_name.to_string())
+            .collect();
+        assert_eq!(names, vec!["example7", "example9"]);
+
+        //a prefix of a network prefix doesn't match
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 10,
+            cursor: None,
+            network_prefix: Some("ju".to_string()),
+            ..Default::default()
+        });
+        let name_records_msg: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(name_records_msg.name_records.len(), 0);
+    }
+
+    #[test]
+    fn test_query_name_records_by_created_at() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |inst_msg| {
+            inst_msg.allowed_hrps = vec!["cosmwasm".to_string(), "juno".to_string(), "osmo".to_string()];
+        });
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        // register names out of alphabetical order, one block apart
+        let mut created_at = vec![];
+        for (name, network_den) in [
+            ("delta", "juno"),
+            ("alpha", "juno"),
+            ("echo", "juno"),
+            ("bravo", "osmo"),
+            ("charlie", "juno"),
+        ] {
+            app.update_block(next_block);
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(
+                    format!("{}_site_address", name)
+                        .into_bech32_with_prefix(network_den)
+                        .to_string(),
+                ),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+            created_at.push(app.block_info().time);
+        }
+
+        let query_names = |app: &App, query_msg: &QueryMsg| -> Vec<String> {
+            let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), query_msg).unwrap();
+            resp.name_records
+                .into_iter()
+                .map(|r| r.cannonical_name.into_string())
+                .collect()
+        };
+
+        //oldest names first
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 2,
+            sort_by: Some(NameRecordsSortKey::CreatedAt),
+            ..Default::default()
+        });
+        assert_eq!(query_names(&app, &query_msg), vec!["delta", "alpha"]);
+
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 2,
+            cursor: Some("alpha".parse().unwrap()),
+            sort_by: Some(NameRecordsSortKey::CreatedAt),
+            ..Default::default()
+        });
+        assert_eq!(query_names(&app, &query_msg), vec!["echo", "bravo"]);
+
+        //newest names first
+        let query_msg = msg::QueryMsg::RecentNameRecords { limit: 3, cursor: None };
+        assert_eq!(query_names(&app, &query_msg), vec!["charlie", "bravo", "echo"]);
+
+        let query_msg = msg::QueryMsg::RecentNameRecords {
+            limit: 3,
+            cursor: Some("echo".parse().unwrap()),
+        };
+        assert_eq!(query_names(&app, &query_msg), vec!["alpha", "delta"]);
+
+        //names in descending order
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 10,
+            cursor: Some("charlie".parse().unwrap()),
+            order: Some(SortOrder::Descending),
+            ..Default::default()
+        });
+        assert_eq!(query_names(&app, &query_msg), vec!["bravo", "alpha"]);
+
+        //names created strictly between alpha and charlie
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 10,
+            sort_by: Some(NameRecordsSortKey::CreatedAt),
+            created_after: Some(created_at[1]),
+            created_before: Some(created_at[4]),
+            ..Default::default()
+        });
+        assert_eq!(query_names(&app, &query_msg), vec!["echo", "bravo"]);
+
+        //newest juno names
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 10,
+            network_prefix: Some("juno".to_string()),
+            order: Some(SortOrder::Descending),
+            sort_by: Some(NameRecordsSortKey::CreatedAt),
+            ..Default::default()
+        });
+        assert_eq!(query_names(&app, &query_msg), vec!["charlie", "echo", "alpha", "delta"]);
+
+        //date filters need the created_at sort key
+        let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+            limit: 10,
+            created_after: Some(created_at[1]),
+            ..Default::default()
+        });
+        let resp: Result<NameRecordsQueryResponse, StdError> = app.wrap().query_wasm_smart(addr.clone(), &query_msg);
+        assert!(resp.unwrap_err().to_string().contains("ValidationError:"));
+    }
 }
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod test_names_by_contract {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_names_by_contract() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32();
+        let other_site_address = "other_site_cw_address".into_bech32();
+
+        // register 3 names for the same site and one for another site
+        for (name, site) in [
+            ("example0", &site_address),
+            ("example1", &site_address),
+            ("example2", &site_address),
+            ("other", &other_site_address),
+        ] {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(site.to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        //query the names pointing at the site
+        let query_msg = msg::QueryMsg::NamesByContract(NamesByContractQueryMsg {
+            contract: site_address.to_string(),
+            limit: 2,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 2);
+        assert_eq!(resp.name_records[0].cannonical_name, "example0");
+        assert_eq!(resp.name_records[1].cannonical_name, "example1");
+
+        let query_msg = msg::QueryMsg::NamesByContract(NamesByContractQueryMsg {
+            contract: site_address.to_string(),
+            limit: 2,
+            cursor: resp.next_cursor,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 1);
+        assert_eq!(resp.name_records[0].cannonical_name, "example2");
+
+        //the first registered name is used for reverse lookups
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: site_address.to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "example0");
+
+        //once set, only the target contract can change the primary name, not
+        //even the owner of the name
+        let set_primary_msg = ExecuteMsg::SetPrimaryContractName(SetPrimaryContractNameMsg {
+            name: "example1".parse().unwrap(),
+        });
+        for sender in [&owner_address, &name_owner] {
+            let err = app
+                .execute_contract(sender.clone(), addr.clone(), &set_primary_msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                ContractError::NotAuthorized {
+                    reason: "Only the contract or its admin can change its primary name".to_string(),
+                },
+                err.downcast().unwrap()
+            );
+        }
+        let _resp = app
+            .execute_contract(site_address.clone(), addr.clone(), &set_primary_msg, &[])
+            .unwrap();
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "example1");
+
+        //the owner of a name can only fill an empty slot
+        state::CONTRACT_ADDR_2_NAME.remove(app.contract_storage_mut(&addr).as_mut(), &site_address.to_string());
+        let err = app
+            .execute_contract(owner_address.clone(), addr.clone(), &set_primary_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_primary_msg, &[])
+            .unwrap();
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "example1");
+
+        //pointing the primary name elsewhere falls back to another name of the site
+        let update_contract_msg = ExecuteMsg::UpdateContract(UpdateContractMsg {
+            name: "example1".parse().unwrap(),
+            address: Some(other_site_address.to_string()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_contract_msg, &[])
+            .unwrap();
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "example0");
+
+        let query_msg = msg::QueryMsg::NamesByContract(NamesByContractQueryMsg {
+            contract: other_site_address.to_string(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = resp
+            .name_records
+            .into_iter()
+            .map(|r| r.cannonical_name.into_string())
+            .collect();
+        assert_eq!(names, vec!["example1", "other"]);
+
+        //names without a contract aren't listed for an empty contract or network
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: "hosted".parse().unwrap(),
+            address: None,
+            content_hash: Some(ContentHash::Url("https://example.com/site".to_string())),
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+        for contract in ["", "\0"] {
+            let query_msg = msg::QueryMsg::NamesByContract(NamesByContractQueryMsg {
+                contract: contract.to_string(),
+                limit: 10,
+                cursor: None,
+            });
+            let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            assert!(resp.name_records.is_empty());
+
+            let query_msg = msg::QueryMsg::NameRecords(NameRecordsQueryMsg {
+                limit: 10,
+                network_prefix: Some(contract.to_string()),
+                ..Default::default()
+            });
+            let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            assert!(resp.name_records.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_update_contract_keeps_primary_name() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), 1000);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let site_address = "example_site_cw_address".into_bech32();
+        for name in ["zeta", "alpha"] {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(site_address.to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        //re-sending the current contract doesn't change its reverse lookup
+        let update_contract_msg = ExecuteMsg::UpdateContract(UpdateContractMsg {
+            name: "zeta".parse().unwrap(),
+            address: Some(site_address.to_string()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_contract_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::NameRecord {
+            contract: site_address.to_string(),
+        };
+        let resp: PublicNameRecord = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.cannonical_name, "zeta");
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test_names_by_keyword {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_names_by_keyword() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        for (name, keywords) in [
+            ("games", vec!["Fun", "arcade"]),
+            ("memes", vec!["fun"]),
+            ("news", vec!["daily"]),
+        ] {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(format!("{}_site_address", name).into_bech32().to_string()),
+                content_hash: None,
+                meta: Some(NameMetadata {
+                    keywords: Some(keywords.into_iter().map(String::from).collect()),
+                    ..Default::default()
+                }),
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        let query_names = |app: &cw_multi_test::App, keyword: &str, cursor: Option<String>| -> Vec<String> {
+            let query_msg = msg::QueryMsg::NamesByKeyword(NamesByKeywordQueryMsg {
+                keyword: keyword.to_string(),
+                limit: 10,
+                cursor: cursor.map(|name| name.parse().unwrap()),
+            });
+            let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+            resp.name_records
+                .into_iter()
+                .map(|r| r.cannonical_name.into_string())
+                .collect()
+        };
+
+        //keywords are matched case insensitively
+        assert_eq!(query_names(&app, "FUN", None), vec!["games", "memes"]);
+        assert_eq!(query_names(&app, "fun", Some("games".to_string())), vec!["memes"]);
+        assert_eq!(query_names(&app, "arcade", None), vec!["games"]);
+        assert_eq!(query_names(&app, "unknown", None), Vec::<String>::new());
+
+        //changing keywords removes the stale index entries
+        let update_msg = ExecuteMsg::UpdateMetadata(UpdateMetadataMsg {
+            name: "games".parse().unwrap(),
+            meta: NameMetadata {
+                keywords: Some(vec!["retro".to_string()]),
+                ..Default::default()
+            },
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_msg, &[])
+            .unwrap();
+        assert_eq!(query_names(&app, "fun", None), vec!["memes"]);
+        assert_eq!(query_names(&app, "arcade", None), Vec::<String>::new());
+        assert_eq!(query_names(&app, "retro", None), vec!["games"]);
+
+        //updating other fields keeps the keywords indexed
+        let update_msg = ExecuteMsg::UpdateMetadata(UpdateMetadataMsg {
+            name: "games".parse().unwrap(),
+            meta: NameMetadata {
+                title: Some("Games".to_string()),
+                ..Default::default()
+            },
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &update_msg, &[])
+            .unwrap();
+        assert_eq!(query_names(&app, "retro", None), vec!["games"]);
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test_names_by_owner {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_names_by_owner() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+
+        // register 6 names, alternating between the two owners
+        for i in 0..6 {
+            let owner = if i % 2 == 0 {
+                name_owner.clone()
+            } else {
+                owner_address.clone()
+            };
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: owner.clone(),
+                name: format!("example{}", i).parse().unwrap(),
+                address: Some(format!("example_site_address{}", i).into_bech32().to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        //query the first page of names owned by name_owner
+        let query_msg = msg::QueryMsg::NamesByOwner(NamesByOwnerQueryMsg {
+            owner: name_owner.clone(),
+            limit: 2,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 2);
+        assert_eq!(resp.name_records[0].cannonical_name, "example0");
+        assert_eq!(resp.name_records[1].cannonical_name, "example2");
+        assert_eq!(resp.next_cursor, Some("example2".parse().unwrap()));
+
+        //query the next page
+        let query_msg = msg::QueryMsg::NamesByOwner(NamesByOwnerQueryMsg {
+            owner: name_owner.clone(),
+            limit: 2,
+            cursor: resp.next_cursor,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 1);
+        assert_eq!(resp.name_records[0].cannonical_name, "example4");
+        assert_eq!(resp.name_records[0].owner, name_owner);
+
+        //transferring a name moves it to the new owner's list
+        let transfer_msg = ExecuteMsg::Transfer(TransferMsg {
+            name: "example0".parse().unwrap(),
+            owner: owner_address.clone(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &transfer_msg, &[])
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::NamesByOwner(NamesByOwnerQueryMsg {
+            owner: owner_address.clone(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = resp
+            .name_records
+            .into_iter()
+            .map(|r| r.cannonical_name.into_string())
+            .collect();
+        assert_eq!(names, vec!["example0", "example1", "example3", "example5"]);
+
+        //an address without names gets an empty page
+        let query_msg = msg::QueryMsg::NamesByOwner(NamesByOwnerQueryMsg {
+            owner: Addr::unchecked("nobody"),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: NameRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 0);
+        assert_eq!(resp.next_cursor, None);
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod test_primary_name {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_primary_name() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        let new_owner = Addr::unchecked("new_owner");
+        let site_address = "example_site_cw_address".into_bech32();
+
+        //register a name
+        let name = "example".to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address.to_string()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //addresses without a primary name resolve to nothing
+        let query_msg = msg::QueryMsg::PrimaryName {
+            address: name_owner.clone(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+
+        //only the owner or the target can claim a name as primary name
+        let set_msg = ExecuteMsg::SetPrimaryName(SetPrimaryNameMsg {
+            name: Some(name.parse().unwrap()),
+        });
+        let err = app
+            .execute_contract(new_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are neither the owner nor the target of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+        let _resp = app
+            .execute_contract(site_address.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, Some(name.clone()));
+        let query_msg = msg::QueryMsg::PrimaryName {
+            address: site_address.clone(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, Some(name.clone()));
+
+        //transferring the name invalidates the previous owner's primary name
+        let transfer_msg = ExecuteMsg::Transfer(TransferMsg {
+            name: name.parse().unwrap(),
+            owner: new_owner.clone(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &transfer_msg, &[])
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::PrimaryName {
+            address: name_owner.clone(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+
+        //clearing the primary name
+        let set_msg = ExecuteMsg::SetPrimaryName(SetPrimaryNameMsg { name: None });
+        let _resp = app
+            .execute_contract(site_address.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::PrimaryName {
+            address: site_address.clone(),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+    }
+}
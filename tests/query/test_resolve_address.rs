@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod test_resolve_address {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use error::*;
+    use models::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_resolve_address() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+
+        //register a name
+        let name = "example".to_string();
+        let site_address = "example_site_cw_address".into_bech32().to_string();
+        let reg_msg = ExecuteMsg::Register(RegisterMsg {
+            owner: name_owner.clone(),
+            name: name.parse().unwrap(),
+            address: Some(site_address.clone()),
+            content_hash: None,
+            meta: None,
+            voucher: None,
+            referrer: None,
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+            .unwrap();
+
+        //the target contract's chain resolves without an explicit record
+        let query_msg = msg::QueryMsg::ResolveAddress {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("cosmwasm".to_string()),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, Some(site_address.clone()));
+
+        //other chains resolve to nothing until set
+        let juno_address = "example_payment_address".into_bech32_with_prefix("juno").to_string();
+        let query_msg = msg::QueryMsg::ResolveAddress {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("juno".to_string()),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+
+        //addresses must match their chain
+        let set_msg = ExecuteMsg::SetAddress(SetAddressMsg {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("osmo".to_string()),
+            address: juno_address.clone(),
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Invalid address for chain b_osmo".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        let set_msg = ExecuteMsg::SetAddress(SetAddressMsg {
+            name: name.parse().unwrap(),
+            chain: Chain::Evm(1),
+            address: "0x1234".to_string(),
+        });
+        let err = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ValidationError {
+                reason: "Invalid address for chain e_1".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+
+        //only the owner can set addresses, not even the controller
+        let set_controller_msg = ExecuteMsg::SetController(SetControllerMsg {
+            name: name.parse().unwrap(),
+            controller: Some(owner_address.clone()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_controller_msg, &[])
+            .unwrap();
+        let set_msg = ExecuteMsg::SetAddress(SetAddressMsg {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("juno".to_string()),
+            address: juno_address.clone(),
+        });
+        let err = app
+            .execute_contract(owner_address.clone(), addr.clone(), &set_msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAuthorized {
+                reason: "You are not the owner of this name".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let evm_address = "0xAbCdEf0123456789aBcDeF0123456789abCDef01".to_string();
+        let set_msg = ExecuteMsg::SetAddress(SetAddressMsg {
+            name: name.parse().unwrap(),
+            chain: Chain::Evm(1),
+            address: evm_address.clone(),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &set_msg, &[])
+            .unwrap();
+
+        let query_msg = msg::QueryMsg::ResolveAddress {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("juno".to_string()),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, Some(juno_address.clone()));
+
+        let query_msg = msg::QueryMsg::ResolveAddress {
+            name: name.parse().unwrap(),
+            chain: Chain::Evm(1),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, Some(evm_address.to_lowercase()));
+
+        let query_msg = msg::QueryMsg::AddressRecords {
+            name: name.parse().unwrap(),
+        };
+        let resp: AddressRecordsQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(
+            resp.address_records,
+            vec![
+                AddressRecord {
+                    chain: Chain::Bech32("juno".to_string()),
+                    address: juno_address.clone(),
+                },
+                AddressRecord {
+                    chain: Chain::Evm(1),
+                    address: evm_address.to_lowercase(),
+                },
+            ]
+        );
+
+        //unset an address
+        let unset_msg = ExecuteMsg::UnsetAddress(UnsetAddressMsg {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("juno".to_string()),
+        });
+        let _resp = app
+            .execute_contract(name_owner.clone(), addr.clone(), &unset_msg, &[])
+            .unwrap();
+        let query_msg = msg::QueryMsg::ResolveAddress {
+            name: name.parse().unwrap(),
+            chain: Chain::Bech32("juno".to_string()),
+        };
+        let resp: Option<String> = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp, None);
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test_search_names {
+    use crate::test_utils::*;
+    use cosmwasm_std::coins;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{Executor, IntoBech32};
+    use cw_web31_dns::*;
+    use msg::*;
+
+    #[test]
+    fn test_query_search_names() {
+        let name_owner_str = "name_owner".to_string();
+        let owner_str = "owner".to_string();
+        let amount = 1000u128;
+        let mut app = def_app(owner_str.clone(), name_owner_str.clone(), amount);
+        let owner_address = Addr::unchecked(owner_str.clone());
+        let addr = instantiate_dns(&mut app, &owner_address, |_| {});
+
+        let name_owner = Addr::unchecked(name_owner_str.clone());
+        for name in ["poo", "poop", "pooper", "pop", "zoo"] {
+            let reg_msg = ExecuteMsg::Register(RegisterMsg {
+                owner: name_owner.clone(),
+                name: name.parse().unwrap(),
+                address: Some(format!("{}_site_address", name).into_bech32().to_string()),
+                content_hash: None,
+                meta: None,
+                voucher: None,
+                referrer: None,
+            });
+            let _resp = app
+                .execute_contract(name_owner.clone(), addr.clone(), &reg_msg, &coins(1, "juno"))
+                .unwrap();
+        }
+
+        //search for names starting with "Poo"
+        let query_msg = msg::QueryMsg::SearchNames(SearchNamesQueryMsg {
+            query: "Poo".to_string(),
+            limit: 2,
+            cursor: None,
+        });
+        let resp: SearchNamesQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = resp
+            .name_records
+            .iter()
+            .map(|r| r.cannonical_name.to_string())
+            .collect();
+        assert_eq!(names, vec!["poo", "poop"]);
+        assert!(!resp.is_available);
+
+        //the next page stops at the end of the prefix
+        let query_msg = msg::QueryMsg::SearchNames(SearchNamesQueryMsg {
+            query: "Poo".to_string(),
+            limit: 2,
+            cursor: resp.next_cursor,
+        });
+        let resp: SearchNamesQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = resp
+            .name_records
+            .iter()
+            .map(|r| r.cannonical_name.to_string())
+            .collect();
+        assert_eq!(names, vec!["pooper"]);
+
+        //a free name still lists the taken names sharing its prefix
+        let query_msg = msg::QueryMsg::SearchNames(SearchNamesQueryMsg {
+            query: "poops".to_string(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: SearchNamesQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        assert_eq!(resp.name_records.len(), 0);
+        assert!(resp.is_available);
+
+        let query_msg = msg::QueryMsg::SearchNames(SearchNamesQueryMsg {
+            query: "po".to_string(),
+            limit: 10,
+            cursor: None,
+        });
+        let resp: SearchNamesQueryResponse = app.wrap().query_wasm_smart(addr.clone(), &query_msg).unwrap();
+        let names: Vec<String> = resp
+            .name_records
+            .iter()
+            .map(|r| r.cannonical_name.to_string())
+            .collect();
+        assert_eq!(names, vec!["poo", "poop", "pooper", "pop"]);
+        assert!(resp.is_available);
+    }
+}
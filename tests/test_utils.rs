@@ -1,8 +1,12 @@
-use contract::{execute, instantiate, query};
-use cosmwasm_std::{coins, Addr};
-use cw_multi_test::{App, Contract, ContractWrapper};
+use contract::{execute, instantiate, migrate, query};
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Api, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint128,
+};
+use cw_multi_test::{
+    App, AppBuilder, BankKeeper, Contract, ContractWrapper, Executor, MockAddressGenerator, MockApiBech32, WasmKeeper,
+};
 use cw_orch::prelude::Empty;
-use cw_web31_dns::{contract, models, msg, token};
+use cw_web31_dns::{contract, models, msg, query::render::CwWebsiteQueryMsg, token};
 
 pub fn def_app(
     addr1: String,
@@ -23,6 +27,99 @@ pub fn def_app(
 }
 
 pub fn dns_contract() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new(execute, instantiate, query);
+    let contract = ContractWrapper::new(execute, instantiate, query).with_migrate(migrate);
     Box::new(contract)
 }
+
+/// Store and instantiate the dns contract with the owner as its admin. The
+/// default InstantiateMsg charges 1 juno for names of up to 10 characters
+/// pointing at "cosmwasm" contracts, with every optional setting off; the
+/// overrides adjust it for the test.
+pub fn instantiate_dns<A: Api>(
+    app: &mut App<BankKeeper, A>,
+    owner: &Addr,
+    overrides: impl FnOnce(&mut msg::InstantiateMsg),
+) -> Addr {
+    let code_id = app.store_code(dns_contract());
+    let mut inst_msg = msg::InstantiateMsg {
+        price: token::TokenAmount {
+            token: token::Token::Denom("juno".to_string()),
+            amount: Uint128::from(1u128),
+        },
+        fee_recipient: Addr::unchecked("fee_recipient"),
+        max_name_len: 10,
+        allowed_hrps: vec!["cosmwasm".to_string()],
+        verify_contract_targets: false,
+        probe_render_targets: false,
+        require_target_admin: false,
+        gifting: models::GiftingPolicy::Anyone,
+        release_cooldown: 3600,
+        referral_share: 0,
+    };
+    overrides(&mut inst_msg);
+    app.instantiate_contract(code_id, owner.clone(), &inst_msg, &[], "test", Some(owner.to_string()))
+        .unwrap()
+}
+
+/// App on a chain with the "cosmwasm" prefix whose contract addresses look
+/// like real ones, so contracts count as targets on the same chain
+pub type Bech32App = App<BankKeeper, MockApiBech32>;
+
+pub fn def_bech32_app(
+    addrs: &[&Addr],
+    amount: u128,
+) -> Bech32App {
+    AppBuilder::new()
+        .with_api(MockApiBech32::new("cosmwasm"))
+        .with_wasm(WasmKeeper::new().with_address_generator(MockAddressGenerator))
+        .build(|router, _, storage| {
+            for addr in addrs {
+                router.bank.init_balance(storage, addr, coins(amount, "juno")).unwrap();
+            }
+        })
+}
+
+fn noop_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn noop_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn website_query(
+    _deps: Deps,
+    _env: Env,
+    msg: CwWebsiteQueryMsg,
+) -> StdResult<Binary> {
+    let CwWebsiteQueryMsg::Render { path, .. } = msg;
+    to_json_binary(&format!("<html>{}</html>", path))
+}
+
+fn no_query(
+    _deps: Deps,
+    _env: Env,
+    _msg: Empty,
+) -> StdResult<Binary> {
+    Err(StdError::generic_err("no queries"))
+}
+
+/// Contract answering render queries like a website contract
+pub fn website_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(noop_execute, noop_instantiate, website_query))
+}
+
+/// Contract that doesn't answer render queries
+pub fn plain_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(noop_execute, noop_instantiate, no_query))
+}